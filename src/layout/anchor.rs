@@ -0,0 +1,322 @@
+use tui::layout::Rect;
+
+/// A side of a rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// Returns the side across from this one.
+    #[inline]
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Returns true if this side is above or below a rect.
+    #[inline]
+    #[must_use]
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// How an anchored rect lines up with its target along the side it was placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnchorAlign {
+    /// Line up the left / top edges of both rects.
+    #[default]
+    Start,
+    /// Center the anchored rect on the target.
+    Center,
+    /// Line up the right / bottom edges of both rects.
+    End,
+}
+
+/// A rect placed next to a target rect by [`anchor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Anchored {
+    /// The final position and size of the anchored rect.
+    pub rect: Rect,
+    /// The side of the target that the rect was placed on.
+    pub side: Side,
+}
+
+/// Place a rect of the given `size` (width, height) next to the `target` rect on the `preferred_side`.
+///
+/// This is meant for dropdowns, tooltips, and other popups that need to appear next to a specific cell.
+/// The rect is lined up with the start of the target; see [`anchor_aligned`] for other alignments.
+#[inline]
+#[must_use]
+pub fn anchor(target: Rect, size: (u16, u16), preferred_side: Side, bounds: Rect) -> Anchored {
+    anchor_aligned(target, size, preferred_side, AnchorAlign::Start, bounds)
+}
+
+/// Place a rect of the given `size` (width, height) next to the `target` rect on the `preferred_side`, lined up by `align`.
+///
+/// If the rect doesn't fit on the `preferred_side` within the given `bounds`, it is flipped to the opposite side.
+/// If it fits on neither side, it is placed on the side with the most space and shrunk to fit.
+/// The final rect is always clamped to stay inside of the `bounds`.
+#[inline]
+#[must_use]
+pub fn anchor_aligned(
+    target: Rect,
+    size: (u16, u16),
+    preferred_side: Side,
+    align: AnchorAlign,
+    bounds: Rect,
+) -> Anchored {
+    let (width, height) = size;
+
+    let needed = if preferred_side.is_vertical() {
+        height
+    } else {
+        width
+    };
+
+    let preferred_space = space_on_side(target, preferred_side, bounds);
+    let opposite_space = space_on_side(target, preferred_side.opposite(), bounds);
+
+    let final_side = if preferred_space >= needed || preferred_space >= opposite_space {
+        preferred_side
+    } else {
+        preferred_side.opposite()
+    };
+
+    let space = space_on_side(target, final_side, bounds);
+
+    let rect = if final_side.is_vertical() {
+        let height = height.min(space).min(bounds.height);
+        let width = width.min(bounds.width);
+
+        let y = match final_side {
+            Side::Top => target.y.saturating_sub(height),
+            _ => target.bottom(),
+        };
+
+        let x = aligned_pos(target.x, target.width, width, align);
+
+        Rect {
+            x: clamp_pos(x, width, bounds.x, bounds.right()),
+            y: clamp_pos(y, height, bounds.y, bounds.bottom()),
+            width,
+            height,
+        }
+    } else {
+        let width = width.min(space).min(bounds.width);
+        let height = height.min(bounds.height);
+
+        let x = match final_side {
+            Side::Left => target.x.saturating_sub(width),
+            _ => target.right(),
+        };
+
+        let y = aligned_pos(target.y, target.height, height, align);
+
+        Rect {
+            x: clamp_pos(x, width, bounds.x, bounds.right()),
+            y: clamp_pos(y, height, bounds.y, bounds.bottom()),
+            width,
+            height,
+        }
+    };
+
+    Anchored {
+        rect,
+        side: final_side,
+    }
+}
+
+/// Returns the number of characters / lines between the given `side` of the `target` and the edge of the `bounds`.
+fn space_on_side(target: Rect, side: Side, bounds: Rect) -> u16 {
+    match side {
+        Side::Top => target.y.saturating_sub(bounds.y),
+        Side::Bottom => bounds.bottom().saturating_sub(target.bottom()),
+        Side::Left => target.x.saturating_sub(bounds.x),
+        Side::Right => bounds.right().saturating_sub(target.right()),
+    }
+}
+
+fn aligned_pos(target_pos: u16, target_size: u16, size: u16, align: AnchorAlign) -> u16 {
+    match align {
+        AnchorAlign::Start => target_pos,
+        AnchorAlign::Center => target_pos
+            .saturating_add(target_size / 2)
+            .saturating_sub(size / 2),
+        AnchorAlign::End => target_pos.saturating_add(target_size).saturating_sub(size),
+    }
+}
+
+/// Move a span of `size` starting at `pos` so that it stays within `start` and `end`.
+fn clamp_pos(pos: u16, size: u16, start: u16, end: u16) -> u16 {
+    pos.min(end.saturating_sub(size)).max(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{anchor, anchor_aligned, AnchorAlign, Anchored, Side};
+    use tui::layout::Rect;
+
+    const BOUNDS: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 40,
+        height: 20,
+    };
+
+    #[test]
+    fn anchor_below() {
+        let target = Rect::new(5, 5, 10, 1);
+
+        assert_eq!(
+            anchor(target, (12, 4), Side::Bottom, BOUNDS),
+            Anchored {
+                rect: Rect::new(5, 6, 12, 4),
+                side: Side::Bottom
+            }
+        );
+    }
+
+    #[test]
+    fn anchor_above() {
+        let target = Rect::new(5, 5, 10, 1);
+
+        assert_eq!(
+            anchor(target, (12, 4), Side::Top, BOUNDS),
+            Anchored {
+                rect: Rect::new(5, 1, 12, 4),
+                side: Side::Top
+            }
+        );
+    }
+
+    #[test]
+    fn anchor_left_and_right() {
+        let target = Rect::new(15, 5, 10, 3);
+
+        assert_eq!(
+            anchor(target, (6, 2), Side::Left, BOUNDS),
+            Anchored {
+                rect: Rect::new(9, 5, 6, 2),
+                side: Side::Left
+            }
+        );
+
+        assert_eq!(
+            anchor(target, (6, 2), Side::Right, BOUNDS),
+            Anchored {
+                rect: Rect::new(25, 5, 6, 2),
+                side: Side::Right
+            }
+        );
+    }
+
+    #[test]
+    fn anchor_flips_when_offscreen() {
+        let target = Rect::new(5, 17, 10, 1);
+        let anchored = anchor(target, (10, 5), Side::Bottom, BOUNDS);
+
+        assert_eq!(anchored.side, Side::Top);
+        assert_eq!(anchored.rect, Rect::new(5, 12, 10, 5));
+
+        let target = Rect::new(36, 5, 2, 1);
+        let anchored = anchor(target, (8, 1), Side::Right, BOUNDS);
+
+        assert_eq!(anchored.side, Side::Left);
+        assert_eq!(anchored.rect, Rect::new(28, 5, 8, 1));
+    }
+
+    #[test]
+    fn anchor_shrinks_when_neither_side_fits() {
+        let target = Rect::new(0, 8, 10, 1);
+        let anchored = anchor(target, (10, 15), Side::Top, BOUNDS);
+
+        assert_eq!(anchored.side, Side::Bottom);
+        assert_eq!(anchored.rect, Rect::new(0, 9, 10, 11));
+    }
+
+    #[test]
+    fn anchor_clamped_into_bounds() {
+        let target = Rect::new(35, 2, 5, 1);
+        let anchored = anchor(target, (10, 3), Side::Bottom, BOUNDS);
+
+        assert_eq!(anchored.rect, Rect::new(30, 3, 10, 3));
+
+        let anchored = anchor(target, (60, 3), Side::Bottom, BOUNDS);
+        assert_eq!(anchored.rect, Rect::new(0, 3, 40, 3));
+    }
+
+    #[test]
+    fn anchor_alignment() {
+        let target = Rect::new(10, 5, 10, 1);
+
+        let rect = |align| anchor_aligned(target, (4, 2), Side::Bottom, align, BOUNDS).rect;
+
+        assert_eq!(rect(AnchorAlign::Start), Rect::new(10, 6, 4, 2));
+        assert_eq!(rect(AnchorAlign::Center), Rect::new(13, 6, 4, 2));
+        assert_eq!(rect(AnchorAlign::End), Rect::new(16, 6, 4, 2));
+
+        let target = Rect::new(10, 4, 10, 6);
+        let rect = |align| anchor_aligned(target, (3, 2), Side::Right, align, BOUNDS).rect;
+
+        assert_eq!(rect(AnchorAlign::Start), Rect::new(20, 4, 3, 2));
+        assert_eq!(rect(AnchorAlign::Center), Rect::new(20, 6, 3, 2));
+        assert_eq!(rect(AnchorAlign::End), Rect::new(20, 8, 3, 2));
+    }
+
+    #[test]
+    fn anchor_target_outside_bounds() {
+        let target = Rect::new(5, 30, 10, 1);
+
+        let anchored = anchor(target, (10, 5), Side::Top, BOUNDS);
+        assert_eq!(anchored.side, Side::Top);
+        assert_eq!(anchored.rect, Rect::new(5, 15, 10, 5));
+
+        let anchored = anchor(target, (10, 25), Side::Top, BOUNDS);
+        assert_eq!(anchored.rect, Rect::new(5, 0, 10, 20));
+
+        let target = Rect::new(50, 5, 4, 1);
+
+        let anchored = anchor(target, (8, 2), Side::Left, BOUNDS);
+        assert_eq!(anchored.side, Side::Left);
+        assert_eq!(anchored.rect, Rect::new(32, 5, 8, 2));
+
+        let anchored = anchor(target, (60, 2), Side::Left, BOUNDS);
+        assert_eq!(anchored.rect, Rect::new(0, 5, 40, 2));
+    }
+
+    #[test]
+    fn anchor_target_at_edge_of_space() {
+        let target = Rect {
+            x: u16::MAX - 1,
+            y: u16::MAX - 1,
+            width: 4,
+            height: 4,
+        };
+
+        let bounds = Rect::new(u16::MAX - 10, u16::MAX - 10, 10, 10);
+        let anchored = anchor_aligned(target, (4, 4), Side::Bottom, AnchorAlign::End, bounds);
+
+        assert_eq!(anchored.side, Side::Top);
+        assert_eq!(anchored.rect, Rect::new(u16::MAX - 4, u16::MAX - 5, 4, 4));
+    }
+
+    #[test]
+    fn anchor_offset_bounds() {
+        let bounds = Rect::new(10, 10, 20, 10);
+        let target = Rect::new(12, 11, 4, 1);
+        let anchored = anchor(target, (6, 3), Side::Top, bounds);
+
+        assert_eq!(anchored.side, Side::Bottom);
+        assert_eq!(anchored.rect, Rect::new(12, 12, 6, 3));
+    }
+}
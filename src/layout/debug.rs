@@ -0,0 +1,255 @@
+use super::RectExt;
+use smallvec::SmallVec;
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols::line,
+    widgets::Widget,
+};
+
+/// A problem found with a region given to [`LayoutDebug`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutProblem {
+    /// The region at the given index has no width or height.
+    ZeroSized(usize),
+    /// The regions at the given indices overlap each other.
+    Overlap(usize, usize),
+}
+
+/// A widget that draws the outline, name, and size of each given region.
+///
+/// This is meant to be rendered on top of an application to check the results of a layout, or into a [`Buffer`] to snapshot them in tests.
+/// Regions that are zero-sized or overlap another region are drawn with the problem style. See [`LayoutDebug::problems`] to get them directly.
+pub struct LayoutDebug<'a> {
+    regions: &'a [(&'a str, Rect)],
+    style: Style,
+    problem_style: Style,
+}
+
+impl<'a> LayoutDebug<'a> {
+    /// Create a new [`LayoutDebug`] with the given list of named regions.
+    #[inline]
+    #[must_use]
+    pub fn new(regions: &'a [(&'a str, Rect)]) -> Self {
+        Self {
+            regions,
+            style: Style::default().fg(Color::Cyan),
+            problem_style: Style::default().fg(Color::Red),
+        }
+    }
+
+    /// Set the style to draw regions without problems with.
+    #[inline]
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style to draw zero-sized and overlapping regions with.
+    #[inline]
+    #[must_use]
+    pub fn problem_style(mut self, style: Style) -> Self {
+        self.problem_style = style;
+        self
+    }
+
+    /// Returns every problem found with the given regions.
+    #[inline]
+    #[must_use]
+    pub fn problems(&self) -> SmallVec<[LayoutProblem; 4]> {
+        let mut problems = SmallVec::new();
+
+        for (i, (_, rect)) in self.regions.iter().enumerate() {
+            if is_zero_sized(*rect) {
+                problems.push(LayoutProblem::ZeroSized(i));
+                continue;
+            }
+
+            for (j, (_, other)) in self.regions.iter().enumerate().skip(i + 1) {
+                if !is_zero_sized(*other) && rect.intersects(*other) {
+                    problems.push(LayoutProblem::Overlap(i, j));
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn has_problem(problems: &[LayoutProblem], index: usize) -> bool {
+        problems.iter().any(|problem| match *problem {
+            LayoutProblem::ZeroSized(i) => i == index,
+            LayoutProblem::Overlap(i, j) => i == index || j == index,
+        })
+    }
+}
+
+impl Widget for LayoutDebug<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let problems = self.problems();
+
+        for (i, (name, rect)) in self.regions.iter().enumerate() {
+            let style = if Self::has_problem(&problems, i) {
+                self.problem_style
+            } else {
+                self.style
+            };
+
+            if !is_zero_sized(*rect) {
+                draw_outline(*rect, area, buf, style);
+            }
+
+            // Zero-sized regions have no outline, so their label is drawn where they would start instead
+            let label_pos = if rect.width > 2 && rect.height > 1 {
+                (rect.x + 1, rect.y)
+            } else {
+                (rect.x, rect.y)
+            };
+
+            if !area.contains_point(label_pos.0, label_pos.1) {
+                continue;
+            }
+
+            let label = format!("{} {}x{}", name, rect.width, rect.height);
+
+            // Keep the label from covering the right border when it's visible
+            let max_width = if is_zero_sized(*rect) || rect.right() > area.right() {
+                area.right() - label_pos.0
+            } else {
+                rect.right().saturating_sub(label_pos.0 + 1)
+            };
+
+            buf.set_stringn(
+                label_pos.0,
+                label_pos.1,
+                label,
+                max_width.max(1) as usize,
+                style,
+            );
+        }
+    }
+}
+
+fn is_zero_sized(rect: Rect) -> bool {
+    rect.width == 0 || rect.height == 0
+}
+
+/// Draw the outline of `rect` with line symbols, skipping any cells that fall outside of `area`.
+fn draw_outline(rect: Rect, area: Rect, buf: &mut Buffer, style: Style) {
+    let right = rect.right() - 1;
+    let bottom = rect.bottom() - 1;
+
+    let mut set = |x: u16, y: u16, symbol: &str| {
+        if area.contains_point(x, y) {
+            buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+        }
+    };
+
+    for x in rect.x..=right {
+        set(x, rect.y, line::HORIZONTAL);
+        set(x, bottom, line::HORIZONTAL);
+    }
+
+    for y in rect.y..=bottom {
+        set(rect.x, y, line::VERTICAL);
+        set(right, y, line::VERTICAL);
+    }
+
+    set(rect.x, rect.y, line::TOP_LEFT);
+    set(right, rect.y, line::TOP_RIGHT);
+    set(rect.x, bottom, line::BOTTOM_LEFT);
+    set(right, bottom, line::BOTTOM_RIGHT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LayoutDebug, LayoutProblem};
+    use tui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+    fn render(debug: LayoutDebug, width: u16, height: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+
+        debug
+            .style(Style::default())
+            .problem_style(Style::default())
+            .render(area, &mut buf);
+
+        buf
+    }
+
+    #[test]
+    fn debug_outlines() {
+        let regions = [
+            ("left", Rect::new(0, 0, 10, 3)),
+            ("right", Rect::new(10, 0, 10, 3)),
+        ];
+
+        let buf = render(LayoutDebug::new(&regions), 20, 3);
+
+        let expected = Buffer::with_lines(vec![
+            "┌left 10x┐┌right 10┐",
+            "│        ││        │",
+            "└────────┘└────────┘",
+        ]);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn debug_clipped_to_area() {
+        let regions = [("big", Rect::new(2, 1, 30, 30))];
+        let buf = render(LayoutDebug::new(&regions), 10, 3);
+
+        let expected = Buffer::with_lines(vec!["          ", "  ┌big 30x", "  │       "]);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn debug_zero_sized_label() {
+        let regions = [("empty", Rect::new(1, 1, 0, 5))];
+        let buf = render(LayoutDebug::new(&regions), 10, 2);
+
+        let expected = Buffer::with_lines(vec!["          ", " empty 0x5"]);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn debug_problems() {
+        let regions = [
+            ("a", Rect::new(0, 0, 10, 3)),
+            ("b", Rect::new(9, 0, 10, 3)),
+            ("c", Rect::new(0, 3, 0, 3)),
+            ("d", Rect::new(19, 0, 5, 3)),
+            ("e", Rect::new(0, 2, 19, 1)),
+        ];
+
+        let debug = LayoutDebug::new(&regions);
+
+        assert_eq!(
+            debug.problems().as_slice(),
+            [
+                LayoutProblem::Overlap(0, 1),
+                LayoutProblem::Overlap(0, 4),
+                LayoutProblem::Overlap(1, 4),
+                LayoutProblem::ZeroSized(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_no_problems() {
+        let regions = [
+            ("a", Rect::new(0, 0, 10, 3)),
+            ("b", Rect::new(10, 0, 10, 3)),
+        ];
+        assert!(LayoutDebug::new(&regions).problems().is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use super::{solve_sizes, BasicConstraint, RectExt, Side};
+use tui::layout::{Direction, Rect};
+
+/// Split an area into docks along its edges, such as a title bar, status line, or sidebar, with the remaining space in the center.
+///
+/// Docks are solved in the order they are added, and each one takes its space from what the previous docks left over.
+/// This means that the first docks added take up the corners of the area.
+/// When there isn't enough space left, later docks shrink down to nothing instead of overlapping the earlier ones.
+#[derive(Clone)]
+pub struct DockLayout {
+    docks: [(Side, BasicConstraint); 4],
+    num_docks: usize,
+    margin_x: u16,
+    margin_y: u16,
+}
+
+impl DockLayout {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            docks: [(Side::Top, BasicConstraint::Length(0)); 4],
+            num_docks: 0,
+            margin_x: 0,
+            margin_y: 0,
+        }
+    }
+
+    /// Add a dock to the given `side`, sized by the given `constraint`.
+    ///
+    /// The constraint is solved against the height of the remaining area for the top and bottom docks, and the width for the left and right docks.
+    /// If the `side` already has a dock, its constraint is replaced and it keeps its original order.
+    #[inline]
+    #[must_use]
+    pub fn dock(mut self, side: Side, constraint: BasicConstraint) -> Self {
+        let docks = &mut self.docks[..self.num_docks];
+
+        if let Some(dock) = docks.iter_mut().find(|(dock_side, _)| *dock_side == side) {
+            dock.1 = constraint;
+        } else {
+            self.docks[self.num_docks] = (side, constraint);
+            self.num_docks += 1;
+        }
+
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn top(self, constraint: BasicConstraint) -> Self {
+        self.dock(Side::Top, constraint)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bottom(self, constraint: BasicConstraint) -> Self {
+        self.dock(Side::Bottom, constraint)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn left(self, constraint: BasicConstraint) -> Self {
+        self.dock(Side::Left, constraint)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn right(self, constraint: BasicConstraint) -> Self {
+        self.dock(Side::Right, constraint)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn horizontal_margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn vertical_margin(mut self, margin: u16) -> Self {
+        self.margin_y = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self.margin_y = margin;
+        self
+    }
+
+    /// Split the given `area` into its docks and center.
+    ///
+    /// Sides without a dock are returned as zero-sized rects along the matching edge of the center.
+    #[inline]
+    #[must_use]
+    pub fn split(&self, area: Rect) -> DockSplit {
+        let mut remaining = area.pad(self.margin_x, self.margin_y);
+        let mut docked = [None; 4];
+
+        for &(side, constraint) in &self.docks[..self.num_docks] {
+            let total = if side.is_vertical() {
+                remaining.height
+            } else {
+                remaining.width
+            };
+
+            let mut size = [0];
+            solve_sizes(&[constraint], &[], &mut size, total);
+            let [size] = size;
+
+            let (dock, rest) = match side {
+                Side::Top => remaining.split_at(size, Direction::Vertical),
+                Side::Left => remaining.split_at(size, Direction::Horizontal),
+                Side::Bottom => {
+                    let (rest, dock) = remaining.split_at(total - size, Direction::Vertical);
+                    (dock, rest)
+                }
+                Side::Right => {
+                    let (rest, dock) = remaining.split_at(total - size, Direction::Horizontal);
+                    (dock, rest)
+                }
+            };
+
+            docked[side_index(side)] = Some(dock);
+            remaining = rest;
+        }
+
+        let center = remaining;
+
+        let edge = |side| match side {
+            Side::Top => center.lines_from_top(0),
+            Side::Bottom => center.lines_from_bottom(0),
+            Side::Left => center.split_at(0, Direction::Horizontal).0,
+            Side::Right => center.split_at(center.width, Direction::Horizontal).1,
+        };
+
+        let dock = |side| docked[side_index(side)].unwrap_or_else(|| edge(side));
+
+        DockSplit {
+            top: dock(Side::Top),
+            bottom: dock(Side::Bottom),
+            left: dock(Side::Left),
+            right: dock(Side::Right),
+            center,
+        }
+    }
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const fn side_index(side: Side) -> usize {
+    match side {
+        Side::Top => 0,
+        Side::Bottom => 1,
+        Side::Left => 2,
+        Side::Right => 3,
+    }
+}
+
+/// An area split by a [`DockLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DockSplit {
+    pub top: Rect,
+    pub bottom: Rect,
+    pub left: Rect,
+    pub right: Rect,
+    /// The space left over after every dock has been solved.
+    pub center: Rect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DockLayout, DockSplit};
+    use crate::layout::BasicConstraint;
+    use tui::layout::Rect;
+
+    fn chrome() -> DockLayout {
+        DockLayout::new()
+            .top(BasicConstraint::Length(1))
+            .bottom(BasicConstraint::Length(1))
+            .left(BasicConstraint::Percentage(25))
+    }
+
+    #[test]
+    fn dock_chrome() {
+        let split = chrome().split(Rect::new(0, 0, 40, 20));
+
+        assert_eq!(
+            split,
+            DockSplit {
+                top: Rect::new(0, 0, 40, 1),
+                bottom: Rect::new(0, 19, 40, 1),
+                left: Rect::new(0, 1, 10, 18),
+                right: Rect::new(40, 1, 0, 18),
+                center: Rect::new(10, 1, 30, 18),
+            }
+        );
+    }
+
+    #[test]
+    fn dock_order_decides_corners() {
+        let split = DockLayout::new()
+            .left(BasicConstraint::Length(5))
+            .top(BasicConstraint::Length(2))
+            .split(Rect::new(0, 0, 20, 10));
+
+        assert_eq!(split.left, Rect::new(0, 0, 5, 10));
+        assert_eq!(split.top, Rect::new(5, 0, 15, 2));
+        assert_eq!(split.center, Rect::new(5, 2, 15, 8));
+    }
+
+    #[test]
+    fn dock_replaces_side() {
+        let split = chrome()
+            .top(BasicConstraint::Length(3))
+            .split(Rect::new(0, 0, 40, 20));
+
+        assert_eq!(split.top, Rect::new(0, 0, 40, 3));
+        assert_eq!(split.center, Rect::new(10, 3, 30, 16));
+    }
+
+    #[test]
+    fn dock_with_margin() {
+        let split = chrome().margin(1).split(Rect::new(0, 0, 42, 22));
+
+        assert_eq!(split.top, Rect::new(1, 1, 40, 1));
+        assert_eq!(split.center, Rect::new(11, 2, 30, 18));
+    }
+
+    #[test]
+    fn dock_collapses_when_too_small() {
+        let split = DockLayout::new()
+            .top(BasicConstraint::Length(2))
+            .bottom(BasicConstraint::Length(2))
+            .right(BasicConstraint::Length(4))
+            .split(Rect::new(0, 0, 3, 3));
+
+        assert_eq!(split.top, Rect::new(0, 0, 3, 2));
+        assert_eq!(split.bottom, Rect::new(0, 2, 3, 1));
+        assert_eq!(split.right, Rect::new(0, 2, 3, 0));
+        assert_eq!(split.center, Rect::new(0, 2, 0, 0));
+    }
+
+    #[test]
+    fn dock_fill_takes_remaining() {
+        let split = DockLayout::new()
+            .bottom(BasicConstraint::Length(1))
+            .right(BasicConstraint::Fill(1))
+            .split(Rect::new(0, 0, 10, 5));
+
+        assert_eq!(split.right, Rect::new(0, 0, 10, 4));
+        assert_eq!(split.center.width, 0);
+    }
+}
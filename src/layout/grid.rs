@@ -0,0 +1,217 @@
+use super::{BasicConstraint, RectExt, SimpleLayout};
+use tui::layout::{Direction, Rect};
+
+/// Build two-dimensional layouts from a list of row and column [`BasicConstraint`]'s without ever allocating.
+///
+/// This is a faster alternative to nesting [`SimpleLayout::split`] calls for every row.
+#[derive(Clone)]
+pub struct SimpleGrid<const R: usize, const C: usize> {
+    rows: [BasicConstraint; R],
+    columns: [BasicConstraint; C],
+    margin_x: u16,
+    margin_y: u16,
+    row_spacing: u16,
+    column_spacing: u16,
+}
+
+impl<const R: usize, const C: usize> SimpleGrid<R, C> {
+    /// Create a new [`SimpleGrid`] with the given `rows` and `columns` constraints.
+    #[inline]
+    #[must_use]
+    pub const fn new(rows: [BasicConstraint; R], columns: [BasicConstraint; C]) -> Self {
+        Self {
+            rows,
+            columns,
+            margin_x: 0,
+            margin_y: 0,
+            row_spacing: 0,
+            column_spacing: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn horizontal_margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn vertical_margin(mut self, margin: u16) -> Self {
+        self.margin_y = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self.margin_y = margin;
+        self
+    }
+
+    /// Set the number of lines to leave empty between each row.
+    #[inline]
+    #[must_use]
+    pub fn row_spacing(mut self, spacing: u16) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Set the number of characters to leave empty between each column.
+    #[inline]
+    #[must_use]
+    pub fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Set the spacing between both rows and columns.
+    #[inline]
+    #[must_use]
+    pub fn spacing(self, spacing: u16) -> Self {
+        self.row_spacing(spacing).column_spacing(spacing)
+    }
+
+    fn split_axes(&self, area: Rect) -> ([Rect; R], [Rect; C]) {
+        let area = area.pad(self.margin_x, self.margin_y);
+
+        let rows = SimpleLayout::new(Direction::Vertical)
+            .spacing(self.row_spacing)
+            .split(area, self.rows);
+
+        let columns = SimpleLayout::new(Direction::Horizontal)
+            .spacing(self.column_spacing)
+            .split(area, self.columns);
+
+        (rows, columns)
+    }
+
+    /// Split the given `area` into a grid of cells, indexed by row and then by column.
+    #[inline]
+    #[must_use]
+    pub fn split(&self, area: Rect) -> [[Rect; C]; R] {
+        let (rows, columns) = self.split_axes(area);
+        let mut results = [[Rect::default(); C]; R];
+
+        for (result_row, row) in results.iter_mut().zip(rows.iter()) {
+            for (cell, column) in result_row.iter_mut().zip(columns.iter()) {
+                *cell = cell_rect(*row, *column);
+            }
+        }
+
+        results
+    }
+
+    /// Returns a rect spanning `row_span` rows and `column_span` columns, starting from the cell at `row` and `column`.
+    ///
+    /// The span is cut off at the last row / column of the grid, and includes any spacing between the cells it covers.
+    /// Returns `None` if the starting cell is outside of the grid, or if either span is zero.
+    #[inline]
+    #[must_use]
+    pub fn span(
+        &self,
+        area: Rect,
+        row: usize,
+        column: usize,
+        row_span: usize,
+        column_span: usize,
+    ) -> Option<Rect> {
+        if row >= R || column >= C || row_span == 0 || column_span == 0 {
+            return None;
+        }
+
+        let (rows, columns) = self.split_axes(area);
+
+        let last_row = (row + row_span - 1).min(R - 1);
+        let last_column = (column + column_span - 1).min(C - 1);
+
+        let first = cell_rect(rows[row], columns[column]);
+        let last = cell_rect(rows[last_row], columns[last_column]);
+
+        Some(Rect {
+            width: last.right() - first.x,
+            height: last.bottom() - first.y,
+            ..first
+        })
+    }
+}
+
+fn cell_rect(row: Rect, column: Rect) -> Rect {
+    Rect {
+        x: column.x,
+        y: row.y,
+        width: column.width,
+        height: row.height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimpleGrid;
+    use crate::layout::BasicConstraint;
+    use tui::layout::Rect;
+
+    fn test_grid() -> SimpleGrid<2, 3> {
+        SimpleGrid::new(
+            [BasicConstraint::Length(3), BasicConstraint::Fill(1)],
+            [
+                BasicConstraint::Length(10),
+                BasicConstraint::Fill(1),
+                BasicConstraint::Length(10),
+            ],
+        )
+    }
+
+    #[test]
+    fn grid_split() {
+        let cells = test_grid().split(Rect::new(0, 0, 40, 10));
+
+        assert_eq!(cells[0][0], Rect::new(0, 0, 10, 3));
+        assert_eq!(cells[0][1], Rect::new(10, 0, 20, 3));
+        assert_eq!(cells[0][2], Rect::new(30, 0, 10, 3));
+        assert_eq!(cells[1][0], Rect::new(0, 3, 10, 7));
+        assert_eq!(cells[1][1], Rect::new(10, 3, 20, 7));
+        assert_eq!(cells[1][2], Rect::new(30, 3, 10, 7));
+    }
+
+    #[test]
+    fn grid_split_with_margin_and_spacing() {
+        let cells = test_grid()
+            .margin(1)
+            .spacing(1)
+            .split(Rect::new(0, 0, 42, 12));
+
+        assert_eq!(cells[0][0], Rect::new(1, 1, 10, 3));
+        assert_eq!(cells[0][1], Rect::new(12, 1, 18, 3));
+        assert_eq!(cells[1][2], Rect::new(31, 5, 10, 6));
+    }
+
+    #[test]
+    fn grid_span() {
+        let grid = test_grid().spacing(1);
+        let area = Rect::new(0, 0, 40, 10);
+
+        assert_eq!(grid.span(area, 0, 1, 2, 2), Some(Rect::new(11, 0, 29, 10)));
+        assert_eq!(grid.span(area, 1, 0, 1, 1), grid.split(area)[1][0].into());
+    }
+
+    #[test]
+    fn grid_span_clamped() {
+        let grid = test_grid();
+        let area = Rect::new(0, 0, 40, 10);
+
+        assert_eq!(grid.span(area, 1, 1, 5, 5), Some(Rect::new(10, 3, 30, 7)));
+    }
+
+    #[test]
+    fn grid_span_out_of_bounds() {
+        let grid = test_grid();
+        let area = Rect::new(0, 0, 40, 10);
+
+        assert_eq!(grid.span(area, 2, 0, 1, 1), None);
+        assert_eq!(grid.span(area, 0, 3, 1, 1), None);
+        assert_eq!(grid.span(area, 0, 0, 0, 1), None);
+    }
+}
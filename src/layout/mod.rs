@@ -0,0 +1,1218 @@
+pub mod anchor;
+pub mod debug;
+pub mod dock;
+pub mod grid;
+pub mod parse;
+pub mod rect;
+pub mod responsive;
+
+pub use anchor::{anchor, anchor_aligned, AnchorAlign, Anchored, Side};
+pub use debug::{LayoutDebug, LayoutProblem};
+pub use dock::{DockLayout, DockSplit};
+pub use grid::SimpleGrid;
+pub use parse::{parse_list, ParseConstraintError};
+pub use rect::{Insets, RectExt};
+pub use responsive::{Axis, Breakpoint, ResponsiveLayout, ResponsiveSplit};
+
+use smallvec::{smallvec, SmallVec};
+use tui::layout::{Direction, Rect};
+
+/// Build simple layouts much faster than [`tui::layout::Layout`](https://docs.rs/tui/0.14.0/tui/layout/struct.Layout.html) and without ever allocating.
+#[derive(Clone)]
+pub struct SimpleLayout {
+    direction: Direction,
+    margin_x: u16,
+    margin_y: u16,
+    spacing: u16,
+    justify: Justify,
+    remainder: Remainder,
+}
+
+impl SimpleLayout {
+    #[inline]
+    #[must_use]
+    pub const fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            margin_x: 0,
+            margin_y: 0,
+            spacing: 0,
+            justify: Justify::Start,
+            remainder: Remainder::Discard,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn horizontal_margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn vertical_margin(mut self, margin: u16) -> Self {
+        self.margin_y = margin;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.margin_x = margin;
+        self.margin_y = margin;
+        self
+    }
+
+    /// Set the number of characters / lines to leave empty between each cell.
+    #[inline]
+    #[must_use]
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set where any space that the cells don't use up should go.
+    #[inline]
+    #[must_use]
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Set which cells receive the space that can't be divided evenly in [`Self::split_n`] and the other even splits.
+    #[inline]
+    #[must_use]
+    pub fn remainder(mut self, remainder: Remainder) -> Self {
+        self.remainder = remainder;
+        self
+    }
+
+    fn get_padded(&self, area: Rect) -> Rect {
+        area.pad(self.margin_x, self.margin_y)
+    }
+
+    /// Returns the space left for `num_cells` cells in the given `size` once the spacing between them has been taken out.
+    fn available_size(&self, size: u16, num_cells: usize) -> u16 {
+        let gaps = u32::from(self.spacing) * num_cells.saturating_sub(1) as u32;
+        u32::from(size).saturating_sub(gaps) as u16
+    }
+
+    /// Position cells of the given `sizes` within `gen_rect`, taking spacing and justification into account.
+    ///
+    /// The position of each cell is passed to `place` along with its index.
+    fn arrange<F>(&self, gen_rect: GenericRect, sizes: &[u16], mut place: F)
+    where
+        F: FnMut(usize, GenericRect),
+    {
+        let num_cells = sizes.len() as u32;
+        let used = sizes.iter().map(|&size| u32::from(size)).sum::<u32>();
+        let free = u32::from(self.available_size(gen_rect.size, sizes.len())).saturating_sub(used);
+
+        let end = u32::from(gen_rect.pos) + u32::from(gen_rect.size);
+        let mut offset = u32::from(gen_rect.pos);
+
+        for (index, &size) in sizes.iter().enumerate() {
+            let i = index as u32;
+
+            let free_before = match self.justify {
+                Justify::End => free,
+                Justify::Center => free / 2,
+                Justify::SpaceBetween if num_cells > 1 => free * i / (num_cells - 1),
+                Justify::Start | Justify::SpaceBetween => 0,
+                Justify::SpaceAround => free * (i * 2 + 1) / (num_cells * 2),
+            };
+
+            let pos = (offset + free_before).min(end);
+            let clamped_size = u32::from(size).min(end - pos);
+
+            place(index, GenericRect::new(pos as u16, clamped_size as u16));
+
+            offset += clamped_size + u32::from(self.spacing);
+        }
+    }
+
+    /// Position cells of the given `sizes` within the padded `area` along the layout's direction.
+    fn place(&self, area: Rect, sizes: &[u16], results: &mut [Rect]) {
+        let gen_rect = GenericRect::from_dir(&self.direction, area);
+
+        self.arrange(gen_rect, sizes, |i, cell| {
+            results[i] = cell.as_rect(&self.direction, area);
+        });
+    }
+
+    /// Returns `N` evenly divided sizes for the given `size`, with the remainder handed out according to the remainder policy.
+    fn even_sizes<const N: usize>(&self, size: u16) -> [u16; N] {
+        if N == 0 {
+            return [0; N];
+        }
+
+        let available = self.available_size(size, N);
+        let remainder = available % N as u16;
+
+        let mut sizes = [available / N as u16; N];
+
+        match self.remainder {
+            Remainder::Discard => (),
+            Remainder::First => sizes[0] += remainder,
+            Remainder::Last => sizes[N - 1] += remainder,
+            Remainder::Spread => {
+                for size in sizes.iter_mut().take(remainder as usize) {
+                    *size += 1;
+                }
+            }
+        }
+
+        sizes
+    }
+
+    /// Build a layout with `N` evenly sized cells.
+    ///
+    /// Any space that can't be divided evenly is handled by the [`Remainder`] policy set with [`Self::remainder`].
+    #[inline]
+    #[must_use]
+    pub fn split_n<const N: usize>(self, area: Rect) -> [Rect; N] {
+        let area = self.get_padded(area);
+        let gen_rect = GenericRect::from_dir(&self.direction, area);
+
+        let sizes = self.even_sizes::<N>(gen_rect.size);
+
+        let mut results = [Rect::default(); N];
+        self.place(area, &sizes, &mut results);
+
+        results
+    }
+
+    /// Build a layout with two evenly split cells.
+    #[inline]
+    #[must_use]
+    pub fn split_evenly(self, area: Rect) -> EvenSplit {
+        let [left, right] = self.split_n(area);
+        EvenSplit { left, right }
+    }
+
+    /// Build a layout consisting of four evenly sized cells.
+    #[inline]
+    #[must_use]
+    pub fn split_quarters(self, area: Rect) -> QuarterSplit {
+        let [first, second, third, fourth] = self.split_n(area);
+
+        QuarterSplit {
+            first,
+            second,
+            third,
+            fourth,
+        }
+    }
+
+    /// Build a layout consisting of four evenly sized quadrants.
+    ///
+    /// The spacing, justification, and remainder policy apply to both the horizontal and vertical axis.
+    #[inline]
+    #[must_use]
+    pub fn split_quadrants(self, area: Rect) -> QuadrantSplit {
+        let area = self.get_padded(area);
+
+        let horiz_rect = GenericRect::from_dir(&Direction::Horizontal, area);
+        let vert_rect = GenericRect::from_dir(&Direction::Vertical, area);
+
+        let mut columns = [GenericRect::default(); 2];
+        self.arrange(
+            horiz_rect,
+            &self.even_sizes::<2>(horiz_rect.size),
+            |i, cell| {
+                columns[i] = cell;
+            },
+        );
+
+        let mut rows = [GenericRect::default(); 2];
+        self.arrange(
+            vert_rect,
+            &self.even_sizes::<2>(vert_rect.size),
+            |i, cell| {
+                rows[i] = cell;
+            },
+        );
+
+        let quadrant = |column: GenericRect, row: GenericRect| Rect {
+            x: column.pos,
+            y: row.pos,
+            width: column.size,
+            height: row.size,
+        };
+
+        QuadrantSplit {
+            top_left: quadrant(columns[0], rows[0]),
+            top_right: quadrant(columns[1], rows[0]),
+            bottom_left: quadrant(columns[0], rows[1]),
+            bottom_right: quadrant(columns[1], rows[1]),
+        }
+    }
+
+    /// Split the given `area` by the given list of [`BasicConstraint`]'s.
+    ///
+    /// This is a fast alternative to [`tui::layout::Layout::split`](https://docs.rs/tui/0.14.0/tui/layout/struct.Layout.html#method.split).
+    ///
+    /// If the constraints don't fit within the `area`, every cell is shrunk evenly towards its minimum length, and then below it if needed.
+    /// See [`Self::split_prioritized`] to control which cells shrink first.
+    #[inline]
+    #[must_use]
+    pub fn split<const N: usize>(self, area: Rect, constraints: [BasicConstraint; N]) -> [Rect; N] {
+        self.split_prioritized(area, constraints, [0; N])
+    }
+
+    /// Split the given `area` by the given list of [`BasicConstraint`]'s, with a shrink priority for each cell.
+    ///
+    /// When the constraints don't fit within the `area`, the cells with the lowest priority are shrunk towards their minimum length first.
+    /// A cell is never shrunk below its minimum length until every other cell has been shrunk to theirs,
+    /// and after that, cells are shrunk below their minimum length in the same order.
+    /// Only the [`Min`], [`Max`], [`Between`], [`Content`], [`MinLenGrowthPcnt`], and [`MinLenRemaining`] constraints can shrink.
+    ///
+    /// [`Min`]: `BasicConstraint::Min`
+    /// [`Max`]: `BasicConstraint::Max`
+    /// [`Between`]: `BasicConstraint::Between`
+    /// [`Content`]: `BasicConstraint::Content`
+    /// [`MinLenGrowthPcnt`]: `BasicConstraint::MinLenGrowthPcnt`
+    /// [`MinLenRemaining`]: `BasicConstraint::MinLenRemaining`
+    #[inline]
+    #[must_use]
+    pub fn split_prioritized<const N: usize>(
+        self,
+        area: Rect,
+        constraints: [BasicConstraint; N],
+        priorities: [Priority; N],
+    ) -> [Rect; N] {
+        let area = self.get_padded(area);
+        let gen_rect = GenericRect::from_dir(&self.direction, area);
+
+        let mut sizes = [0; N];
+        let available = self.available_size(gen_rect.size, N);
+        solve_sizes(&constraints, &priorities, &mut sizes, available);
+
+        let mut results = [Rect::default(); N];
+        self.place(area, &sizes, &mut results);
+
+        results
+    }
+
+    /// Split the given `area` by a list of [`BasicConstraint`]'s that is only known at runtime, writing each cell to `results`.
+    ///
+    /// This solves the constraints exactly the same way as [`Self::split`].
+    /// If `results` is shorter than `constraints`, only the first `results.len()` constraints are used.
+    /// This will only allocate if there are more than 16 constraints.
+    #[inline]
+    pub fn split_into(self, area: Rect, constraints: &[BasicConstraint], results: &mut [Rect]) {
+        let num_cells = constraints.len().min(results.len());
+        let constraints = &constraints[..num_cells];
+
+        let area = self.get_padded(area);
+        let gen_rect = GenericRect::from_dir(&self.direction, area);
+
+        let mut sizes: SmallVec<[u16; 16]> = smallvec![0; num_cells];
+        let available = self.available_size(gen_rect.size, num_cells);
+        solve_sizes(constraints, &[], &mut sizes, available);
+
+        self.place(area, &sizes, results);
+    }
+
+    /// Split the given `area` by a list of [`BasicConstraint`]'s that is only known at runtime.
+    ///
+    /// Returns a `SmallVec` containing one cell for each constraint. See [`Self::split_into`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn split_slice(self, area: Rect, constraints: &[BasicConstraint]) -> SmallVec<[Rect; 4]> {
+        let mut results = smallvec![Rect::default(); constraints.len()];
+        self.split_into(area, constraints, &mut results);
+        results
+    }
+}
+
+impl Default for SimpleLayout {
+    fn default() -> Self {
+        Self::new(Direction::Horizontal)
+    }
+}
+
+/// Controls where the space left over by a layout's cells is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Place all cells at the start of the area.
+    #[default]
+    Start,
+    /// Place all cells at the end of the area.
+    End,
+    /// Place all cells in the middle of the area.
+    Center,
+    /// Place the leftover space evenly between each cell, with the first and last cells at the edges of the area.
+    SpaceBetween,
+    /// Place the leftover space evenly around each cell, so that the space at the edges of the area is half the space between cells.
+    SpaceAround,
+}
+
+#[derive(Copy, Clone, Default)]
+struct GenericRect {
+    pos: u16,
+    size: u16,
+}
+
+impl GenericRect {
+    const fn new(pos: u16, size: u16) -> Self {
+        Self { pos, size }
+    }
+
+    const fn from_dir(dir: &Direction, area: Rect) -> Self {
+        match dir {
+            Direction::Horizontal => Self::new(area.x, area.width),
+            Direction::Vertical => Self::new(area.y, area.height),
+        }
+    }
+
+    const fn as_rect(self, dir: &Direction, area: Rect) -> Rect {
+        match dir {
+            Direction::Horizontal => self.as_horiz_rect(area),
+            Direction::Vertical => self.as_vert_rect(area),
+        }
+    }
+
+    const fn as_horiz_rect(self, area: Rect) -> Rect {
+        Rect {
+            x: self.pos,
+            width: self.size,
+            ..area
+        }
+    }
+
+    const fn as_vert_rect(self, area: Rect) -> Rect {
+        Rect {
+            y: self.pos,
+            height: self.size,
+            ..area
+        }
+    }
+}
+
+/// Controls which cells receive the space left over when an area can't be divided evenly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Remainder {
+    /// Leave the remaining space unused.
+    #[default]
+    Discard,
+    /// Give all of the remaining space to the first cell.
+    First,
+    /// Give all of the remaining space to the last cell.
+    Last,
+    /// Give one character / line of the remaining space to each cell, starting from the first.
+    Spread,
+}
+
+pub type Length = u16;
+pub type RemainingLength = Length;
+pub type Percentage = u16;
+pub type Permille = u16;
+pub type Weight = u16;
+/// How important it is for a cell to keep its size. Cells with a lower priority are shrunk first.
+pub type Priority = u8;
+
+/// Area constraints that are easy to calculate.
+///
+/// This is similar to [`tui::layout::Constraint`](https://docs.rs/tui/0.14.0/tui/layout/enum.Constraint.html), but only
+/// supports units that are easy to solve for.
+///
+/// The [`MinLenGrowthPcnt`] and [`MinLenRemaining`] constraints are meant to be used only when the remaining constraints total size is well known.
+/// For example:
+///
+/// ```notest
+/// [
+///     Self::MinLenGrowthPcnt(5, 30),
+///     Self::Percentage(15),
+///     Self::Percentage(15),
+/// ]
+/// ```
+///
+/// In the above example, we know that the constraints following [`MinLenGrowthPcnt`] total 30%, so we should use that as our growth percentage.
+/// If you use a growth percentage that doesn't line up exactly with the amount of the space the remaining constraints use up, you may get odd results.
+/// Therefore, it is recommended to only use [`Percentage`] constraints following [`MinLenGrowthPcnt`], and [`Length`] constraints following [`MinLenRemaining`].
+/// Manually specifying the amount of space left avoids the use of a cassowary solver.
+///
+/// In most cases, the [`Fill`] and [`Ratio`] constraints are a simpler way to take up the remaining space,
+/// as they are solved only after every other constraint has been:
+///
+/// ```notest
+/// [
+///     Self::Length(10),
+///     Self::Fill(1),
+///     Self::Percentage(15),
+/// ]
+/// ```
+///
+/// [`Length`]: `Self::Length`
+/// [`Percentage`]: `Self::Percentage`
+/// [`MinLenGrowthPcnt`]: `Self::MinLenGrowthPcnt`
+/// [`MinLenRemaining`]: `Self::MinLenRemaining`
+/// [`Fill`]: `Self::Fill`
+/// [`Ratio`]: `Self::Ratio`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasicConstraint {
+    /// Number of characters / lines.
+    Length(Length),
+    /// Percentage of the entire area from 0 - 100.
+    Percentage(Percentage),
+    /// Permille (tenths of a percent) of the entire area from 0 - 1000.
+    ///
+    /// This can be used in place of [`Percentage`](`Self::Percentage`) when a finer split is needed.
+    Permille(Permille),
+    /// A minimum length that is expanded by the given percentage from 0 - 100.
+    ///
+    /// This can be used as a substitute for [`tui::layout::Constraint::Min`](https://docs.rs/tui/0.14.0/tui/layout/enum.Constraint.html#variant.Min)
+    /// in simple layouts. It is only recommended to use this constraint in cases where all subsequent constraints are [`Percentage`](`Self::Percentage`)'s.
+    MinLenGrowthPcnt(Length, Percentage),
+    /// A minimum length that expands up to a specified `RemainingLength`.
+    ///
+    /// This can be used as a substitute for [`tui::layout::Constraint::Min`](https://docs.rs/tui/0.14.0/tui/layout/enum.Constraint.html#variant.Min)
+    /// in simple layouts. It is only recommended to use this constraint in cases where all subsequent constraints are [`Length`](`Self::Length`)'s.
+    MinLenRemaining(Length, RemainingLength),
+    /// A fraction of the space left over after all fixed constraints have been solved.
+    ///
+    /// The first value is the numerator, and the second is the denominator. A denominator of 0 always results in an empty cell.
+    Ratio(u16, u16),
+    /// A share of the space left over after all other constraints have been solved.
+    ///
+    /// The leftover space is split between each [`Fill`](`Self::Fill`) constraint based on its weight relative to the others.
+    /// Any space that can't be divided evenly is handed out one character / line at a time, starting from the first [`Fill`](`Self::Fill`).
+    Fill(Weight),
+    /// At least the given number of characters / lines.
+    ///
+    /// Any space left over after all other constraints have been solved is shared with the [`Fill`](`Self::Fill`) constraints as if this was a `Fill(1)`.
+    Min(Length),
+    /// At most the given number of characters / lines.
+    ///
+    /// When there isn't enough space, this shrinks evenly with the other cells of the same priority, and can be shrunk down to zero.
+    Max(Length),
+    /// A length ranging from a minimum to a maximum number of characters / lines.
+    ///
+    /// This will take up its maximum length when there is enough space, and shrink down to its minimum length otherwise.
+    Between(Length, Length),
+    /// The preferred and minimum size of a widget's content, as reported by [`Measure`](`crate::widgets::Measure`).
+    ///
+    /// This will take up its preferred length when there is enough space, and shrink down to its minimum length otherwise.
+    Content(Length, Length),
+}
+
+impl BasicConstraint {
+    /// Returns the length this constraint can be shrunk down to when there isn't enough space, if it can shrink at all.
+    #[inline]
+    #[must_use]
+    pub fn min_len(self) -> Option<Length> {
+        match self {
+            Self::MinLenGrowthPcnt(min, _)
+            | Self::MinLenRemaining(min, _)
+            | Self::Min(min)
+            | Self::Between(min, _)
+            | Self::Content(_, min) => Some(min),
+            Self::Max(_) => Some(0),
+            Self::Length(_)
+            | Self::Percentage(_)
+            | Self::Permille(_)
+            | Self::Ratio(..)
+            | Self::Fill(_) => None,
+        }
+    }
+
+    fn fill_weight(self) -> Option<Weight> {
+        match self {
+            Self::Fill(weight) => Some(weight),
+            Self::Min(_) => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// An evenly split layout.
+#[derive(Clone)]
+pub struct EvenSplit {
+    /// The left / top cell.
+    pub left: Rect,
+    /// The right / bottom cell.
+    pub right: Rect,
+}
+
+/// A layout consisting of four evenly sized cells.
+#[derive(Clone)]
+pub struct QuarterSplit {
+    pub first: Rect,
+    pub second: Rect,
+    pub third: Rect,
+    pub fourth: Rect,
+}
+
+/// A layout consisting of four evenly sized quadrants.
+#[derive(Clone)]
+pub struct QuadrantSplit {
+    pub top_left: Rect,
+    pub top_right: Rect,
+    pub bottom_left: Rect,
+    pub bottom_right: Rect,
+}
+
+/// Solve the size of each given constraint into `sizes` so that they fit within `total`.
+///
+/// This is done in two passes: the first pass solves every fixed constraint in order, and the second pass
+/// splits whatever space is left between the flexible constraints.
+///
+/// If the fixed constraints don't fit, cells are shrunk towards their minimum length in the order given by `priorities`.
+/// Cells without a priority are treated as having a priority of 0.
+fn solve_sizes(
+    constraints: &[BasicConstraint],
+    priorities: &[Priority],
+    sizes: &mut [u16],
+    total: u16,
+) {
+    let mut offset: u32 = 0;
+    let mut total_weight: u32 = 0;
+
+    for (size, &constraint) in sizes.iter_mut().zip(constraints) {
+        *size = match constraint {
+            BasicConstraint::Length(len)
+            | BasicConstraint::Min(len)
+            | BasicConstraint::Max(len) => len,
+            BasicConstraint::Percentage(pcnt) => fast_rounded_percentage(total, pcnt),
+            BasicConstraint::Permille(permille) => fast_rounded_permille(total, permille),
+            BasicConstraint::MinLenGrowthPcnt(min, pcnt) => {
+                let value = fast_rounded_percentage(total, pcnt);
+                value.max(min)
+            }
+            BasicConstraint::MinLenRemaining(min, remaining) => {
+                let used = offset + u32::from(remaining);
+                min.max(u32::from(total).saturating_sub(used) as u16)
+            }
+            BasicConstraint::Between(min, max) => min.max(max),
+            BasicConstraint::Content(preferred, min) => preferred.max(min),
+            BasicConstraint::Ratio(..) | BasicConstraint::Fill(_) => 0,
+        };
+
+        if let Some(weight) = constraint.fill_weight() {
+            total_weight += u32::from(weight);
+        }
+
+        offset += u32::from(*size);
+    }
+
+    if offset > u32::from(total) {
+        shrink_sizes(constraints, priorities, sizes, offset - u32::from(total));
+    }
+
+    // Any overflow that couldn't be shrunk away is cut off from the last cells
+    let mut offset = 0;
+
+    for size in sizes.iter_mut() {
+        *size = (*size).min(total - offset);
+        offset += *size;
+    }
+
+    let remaining = total - offset;
+
+    if remaining == 0 {
+        return;
+    }
+
+    let mut left = remaining;
+
+    for (size, &constraint) in sizes.iter_mut().zip(constraints) {
+        if let BasicConstraint::Ratio(num, den) = constraint {
+            let value = if den == 0 {
+                0
+            } else {
+                (u32::from(remaining) * u32::from(num) / u32::from(den)) as u16
+            };
+
+            *size = value.min(left);
+            left -= *size;
+        }
+    }
+
+    if left == 0 || total_weight == 0 {
+        return;
+    }
+
+    let fill_space = left;
+
+    for (size, &constraint) in sizes.iter_mut().zip(constraints) {
+        if let Some(weight) = constraint.fill_weight() {
+            let share = (u32::from(fill_space) * u32::from(weight) / total_weight) as u16;
+
+            *size += share;
+            left -= share;
+        }
+    }
+
+    // Hand out the space lost to integer division one at a time
+    for (size, &constraint) in sizes.iter_mut().zip(constraints) {
+        if left == 0 {
+            break;
+        }
+
+        if matches!(constraint.fill_weight(), Some(weight) if weight > 0) {
+            *size += 1;
+            left -= 1;
+        }
+    }
+}
+
+/// Shrink the given `sizes` by `overflow`, starting with the cells that have the lowest priority.
+///
+/// Cells sharing the same priority are shrunk evenly, and no cell is shrunk below its minimum length until every cell
+/// has been shrunk down to its minimum. Cells that can't shrink, such as [`BasicConstraint::Length`], are left as is.
+fn shrink_sizes(
+    constraints: &[BasicConstraint],
+    priorities: &[Priority],
+    sizes: &mut [u16],
+    overflow: u32,
+) {
+    let overflow = shrink_to_floor(priorities, sizes, overflow, |i| constraints[i].min_len());
+    shrink_to_floor(priorities, sizes, overflow, |i| {
+        constraints[i].min_len().map(|_| 0)
+    });
+}
+
+/// Shrink the given `sizes` by `overflow` in priority order, without shrinking any cell below the length returned by `floor`.
+///
+/// Cells without a floor aren't shrunk at all. Returns the overflow that couldn't be removed.
+fn shrink_to_floor<F>(
+    priorities: &[Priority],
+    sizes: &mut [u16],
+    mut overflow: u32,
+    floor: F,
+) -> u32
+where
+    F: Fn(usize) -> Option<u16>,
+{
+    let priority = |i: usize| priorities.get(i).copied().unwrap_or(0);
+    let slack = |i: usize, size: u16| floor(i).map_or(0, |min| size.saturating_sub(min));
+
+    let mut level = None;
+
+    while overflow > 0 {
+        // Find the next lowest priority that still has a cell that can shrink
+        let next_level = (0..sizes.len())
+            .filter(|&i| slack(i, sizes[i]) > 0)
+            .map(priority)
+            .filter(|&p| Some(p) > level)
+            .min();
+
+        let Some(cur_level) = next_level else {
+            break;
+        };
+
+        while overflow > 0 {
+            let num_cells = (0..sizes.len())
+                .filter(|&i| priority(i) == cur_level && slack(i, sizes[i]) > 0)
+                .count() as u32;
+
+            if num_cells == 0 {
+                break;
+            }
+
+            let share = (overflow / num_cells).max(1);
+
+            for i in (0..sizes.len()).rev() {
+                if priority(i) != cur_level {
+                    continue;
+                }
+
+                let take = share.min(u32::from(slack(i, sizes[i]))).min(overflow);
+
+                sizes[i] -= take as u16;
+                overflow -= take;
+
+                if overflow == 0 {
+                    break;
+                }
+            }
+        }
+
+        level = Some(cur_level);
+    }
+
+    overflow
+}
+
+/// Calculate a rounded percentage `pcnt` of the given `value` without floating point math for the best performance.
+fn fast_rounded_percentage(value: u16, pcnt: Percentage) -> u16 {
+    fast_rounded_fraction(value, pcnt, 100)
+}
+
+/// Calculate a rounded permille `permille` of the given `value` without floating point math for the best performance.
+fn fast_rounded_permille(value: u16, permille: Permille) -> u16 {
+    fast_rounded_fraction(value, permille, 1000)
+}
+
+/// Calculate `parts` out of `whole` of the given `value`, rounded to the nearest integer.
+///
+/// The math is done with 32-bit integers so that it can't overflow, and the result saturates at `u16::MAX`.
+fn fast_rounded_fraction(value: u16, parts: u16, whole: u16) -> u16 {
+    let mult = u32::from(value) * u32::from(parts);
+    let whole = u32::from(whole);
+    let result = (mult / whole) + u32::from(mult % whole >= whole / 2);
+
+    result.min(u32::from(u16::MAX)) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BasicConstraint, Justify, Remainder, SimpleLayout};
+    use tui::layout::{Direction, Rect};
+
+    fn widths<const N: usize>(width: u16, constraints: [BasicConstraint; N]) -> [u16; N] {
+        let area = Rect::new(0, 0, width, 1);
+        let rects = SimpleLayout::new(Direction::Horizontal).split(area, constraints);
+
+        let mut results = [0; N];
+
+        for (result, rect) in results.iter_mut().zip(rects.iter()) {
+            *result = rect.width;
+        }
+
+        results
+    }
+
+    #[test]
+    fn split_fixed() {
+        let result = widths(
+            20,
+            [BasicConstraint::Length(5), BasicConstraint::Percentage(50)],
+        );
+
+        assert_eq!(result, [5, 10]);
+    }
+
+    #[test]
+    fn split_fill_takes_remaining() {
+        let result = widths(
+            20,
+            [
+                BasicConstraint::Length(5),
+                BasicConstraint::Fill(1),
+                BasicConstraint::Percentage(25),
+            ],
+        );
+
+        assert_eq!(result, [5, 10, 5]);
+    }
+
+    #[test]
+    fn split_fill_weighted() {
+        let result = widths(
+            30,
+            [
+                BasicConstraint::Fill(1),
+                BasicConstraint::Fill(2),
+                BasicConstraint::Length(6),
+            ],
+        );
+
+        assert_eq!(result, [8, 16, 6]);
+    }
+
+    #[test]
+    fn split_fill_remainder_goes_to_first() {
+        let result = widths(
+            11,
+            [
+                BasicConstraint::Fill(1),
+                BasicConstraint::Fill(1),
+                BasicConstraint::Fill(1),
+            ],
+        );
+
+        assert_eq!(result, [4, 4, 3]);
+    }
+
+    #[test]
+    fn split_ratio_of_remaining() {
+        let result = widths(
+            24,
+            [
+                BasicConstraint::Length(4),
+                BasicConstraint::Ratio(1, 4),
+                BasicConstraint::Fill(1),
+            ],
+        );
+
+        assert_eq!(result, [4, 5, 15]);
+    }
+
+    #[test]
+    fn split_ratio_zero_denominator() {
+        let result = widths(10, [BasicConstraint::Ratio(1, 0), BasicConstraint::Fill(1)]);
+        assert_eq!(result, [0, 10]);
+    }
+
+    #[test]
+    fn split_fill_no_space() {
+        let result = widths(10, [BasicConstraint::Length(12), BasicConstraint::Fill(1)]);
+        assert_eq!(result, [10, 0]);
+    }
+
+    #[test]
+    fn split_min_grows() {
+        let result = widths(
+            20,
+            [
+                BasicConstraint::Min(5),
+                BasicConstraint::Fill(1),
+                BasicConstraint::Length(3),
+            ],
+        );
+
+        assert_eq!(result, [11, 6, 3]);
+    }
+
+    #[test]
+    fn split_max_and_between_fit() {
+        let result = widths(
+            20,
+            [
+                BasicConstraint::Max(4),
+                BasicConstraint::Between(2, 6),
+                BasicConstraint::Fill(1),
+            ],
+        );
+
+        assert_eq!(result, [4, 6, 10]);
+    }
+
+    #[test]
+    fn split_overflow_shrinks_evenly() {
+        let result = widths(
+            10,
+            [
+                BasicConstraint::Between(2, 6),
+                BasicConstraint::Between(2, 6),
+            ],
+        );
+
+        assert_eq!(result, [5, 5]);
+    }
+
+    #[test]
+    fn split_overflow_keeps_rigid_cells() {
+        let result = widths(
+            10,
+            [
+                BasicConstraint::Length(4),
+                BasicConstraint::Between(2, 8),
+                BasicConstraint::Length(4),
+            ],
+        );
+
+        assert_eq!(result, [4, 2, 4]);
+    }
+
+    #[test]
+    fn split_overflow_shrinks_max_first() {
+        let result = widths(10, [BasicConstraint::Min(6), BasicConstraint::Max(8)]);
+
+        assert_eq!(result, [6, 4]);
+    }
+
+    #[test]
+    fn split_overflow_by_priority() {
+        let area = Rect::new(0, 0, 12, 1);
+
+        let [first, second, third] = SimpleLayout::new(Direction::Horizontal).split_prioritized(
+            area,
+            [
+                BasicConstraint::Between(2, 6),
+                BasicConstraint::Between(2, 6),
+                BasicConstraint::Between(2, 6),
+            ],
+            [2, 0, 1],
+        );
+
+        assert_eq!(first.width, 6);
+        assert_eq!(second.width, 2);
+        assert_eq!(third.width, 4);
+    }
+
+    #[test]
+    fn split_content_shrinks_to_min() {
+        let constraints = [
+            BasicConstraint::Content(8, 3),
+            BasicConstraint::Length(4),
+            BasicConstraint::Fill(1),
+        ];
+
+        assert_eq!(widths(20, constraints), [8, 4, 8]);
+        assert_eq!(widths(9, constraints), [5, 4, 0]);
+        assert_eq!(widths(5, constraints), [1, 4, 0]);
+    }
+
+    #[test]
+    fn split_overflow_below_min() {
+        let result = widths(6, [BasicConstraint::Between(4, 6), BasicConstraint::Min(4)]);
+
+        assert_eq!(result, [3, 3]);
+    }
+
+    #[test]
+    fn split_overflow_below_min_keeps_every_cell() {
+        let result = widths(8, [BasicConstraint::Min(5); 3]);
+
+        assert_eq!(result, [3, 3, 2]);
+    }
+
+    #[test]
+    fn split_overflow_below_min_by_priority() {
+        let area = Rect::new(0, 0, 8, 1);
+
+        let [first, second, third] = SimpleLayout::new(Direction::Horizontal).split_prioritized(
+            area,
+            [BasicConstraint::Min(4); 3],
+            [1, 0, 1],
+        );
+
+        assert_eq!(first.width, 4);
+        assert_eq!(second.width, 0);
+        assert_eq!(third.width, 4);
+    }
+
+    #[test]
+    fn split_overflow_max_shrinks_evenly() {
+        let result = widths(8, [BasicConstraint::Between(2, 6), BasicConstraint::Max(6)]);
+
+        assert_eq!(result, [4, 4]);
+    }
+
+    #[test]
+    fn split_with_spacing() {
+        let area = Rect::new(0, 0, 12, 1);
+        let [left, mid, right] = SimpleLayout::new(Direction::Horizontal)
+            .spacing(1)
+            .split(area, [BasicConstraint::Fill(1); 3]);
+
+        assert_eq!(left, Rect::new(0, 0, 4, 1));
+        assert_eq!(mid, Rect::new(5, 0, 3, 1));
+        assert_eq!(right, Rect::new(9, 0, 3, 1));
+    }
+
+    #[test]
+    fn split_spacing_larger_than_area() {
+        let area = Rect::new(0, 0, 4, 1);
+        let [left, right] = SimpleLayout::new(Direction::Horizontal)
+            .spacing(6)
+            .split(area, [BasicConstraint::Fill(1); 2]);
+
+        assert_eq!(left, Rect::new(0, 0, 0, 1));
+        assert_eq!(right, Rect::new(4, 0, 0, 1));
+    }
+
+    fn justified(justify: Justify) -> [u16; 3] {
+        let area = Rect::new(0, 0, 20, 1);
+        let rects = SimpleLayout::new(Direction::Horizontal)
+            .spacing(1)
+            .justify(justify)
+            .split(area, [BasicConstraint::Length(2); 3]);
+
+        [rects[0].x, rects[1].x, rects[2].x]
+    }
+
+    #[test]
+    fn split_justify() {
+        assert_eq!(justified(Justify::Start), [0, 3, 6]);
+        assert_eq!(justified(Justify::End), [12, 15, 18]);
+        assert_eq!(justified(Justify::Center), [6, 9, 12]);
+        assert_eq!(justified(Justify::SpaceBetween), [0, 9, 18]);
+        assert_eq!(justified(Justify::SpaceAround), [2, 9, 16]);
+    }
+
+    #[test]
+    fn split_evenly_with_spacing() {
+        let area = Rect::new(0, 0, 11, 4);
+        let split = SimpleLayout::new(Direction::Horizontal)
+            .spacing(1)
+            .split_evenly(area);
+
+        assert_eq!(split.left, Rect::new(0, 0, 5, 4));
+        assert_eq!(split.right, Rect::new(6, 0, 5, 4));
+    }
+
+    #[test]
+    fn split_quarters_centered() {
+        let area = Rect::new(0, 0, 1, 10);
+        let split = SimpleLayout::new(Direction::Vertical)
+            .justify(Justify::Center)
+            .split_quarters(area);
+
+        assert_eq!(split.first, Rect::new(0, 1, 1, 2));
+        assert_eq!(split.fourth, Rect::new(0, 7, 1, 2));
+    }
+
+    fn even_widths<const N: usize>(width: u16, remainder: Remainder) -> [u16; N] {
+        let area = Rect::new(0, 0, width, 1);
+        let rects = SimpleLayout::new(Direction::Horizontal)
+            .remainder(remainder)
+            .split_n::<N>(area);
+
+        let mut results = [0; N];
+
+        for (result, rect) in results.iter_mut().zip(rects.iter()) {
+            *result = rect.width;
+        }
+
+        results
+    }
+
+    #[test]
+    fn split_n_remainder() {
+        assert_eq!(even_widths::<4>(11, Remainder::Discard), [2, 2, 2, 2]);
+        assert_eq!(even_widths::<4>(11, Remainder::First), [5, 2, 2, 2]);
+        assert_eq!(even_widths::<4>(11, Remainder::Last), [2, 2, 2, 5]);
+        assert_eq!(even_widths::<4>(11, Remainder::Spread), [3, 3, 3, 2]);
+    }
+
+    #[test]
+    fn split_n_empty() {
+        assert_eq!(even_widths::<0>(11, Remainder::Spread), []);
+    }
+
+    #[test]
+    fn split_evenly_odd_width() {
+        let area = Rect::new(0, 0, 11, 1);
+        let split = SimpleLayout::new(Direction::Horizontal)
+            .remainder(Remainder::Last)
+            .split_evenly(area);
+
+        assert_eq!(split.left, Rect::new(0, 0, 5, 1));
+        assert_eq!(split.right, Rect::new(5, 0, 6, 1));
+    }
+
+    #[test]
+    fn split_quadrants_with_spacing() {
+        let area = Rect::new(1, 1, 9, 5);
+        let split = SimpleLayout::new(Direction::Horizontal)
+            .spacing(1)
+            .split_quadrants(area);
+
+        assert_eq!(split.top_left, Rect::new(1, 1, 4, 2));
+        assert_eq!(split.top_right, Rect::new(6, 1, 4, 2));
+        assert_eq!(split.bottom_left, Rect::new(1, 4, 4, 2));
+        assert_eq!(split.bottom_right, Rect::new(6, 4, 4, 2));
+    }
+
+    #[test]
+    fn split_slice_matches_split() {
+        let constraints = [
+            BasicConstraint::Length(3),
+            BasicConstraint::Percentage(20),
+            BasicConstraint::Between(2, 8),
+            BasicConstraint::Fill(2),
+            BasicConstraint::Ratio(1, 3),
+        ];
+
+        let layout = SimpleLayout::new(Direction::Vertical)
+            .margin(1)
+            .spacing(1)
+            .justify(Justify::Center);
+
+        for height in [0, 5, 17, 40] {
+            let area = Rect::new(0, 0, 10, height);
+
+            let fixed = layout.clone().split(area, constraints);
+            let dynamic = layout.clone().split_slice(area, &constraints);
+
+            assert_eq!(fixed, dynamic.as_slice());
+        }
+    }
+
+    #[test]
+    fn split_into_short_results() {
+        let area = Rect::new(0, 0, 10, 1);
+        let mut results = [Rect::default(); 1];
+
+        SimpleLayout::new(Direction::Horizontal).split_into(
+            area,
+            &[BasicConstraint::Fill(1), BasicConstraint::Fill(1)],
+            &mut results,
+        );
+
+        assert_eq!(results, [Rect::new(0, 0, 10, 1)]);
+    }
+
+    #[test]
+    fn split_permille() {
+        let result = widths(
+            80,
+            [
+                BasicConstraint::Permille(125),
+                BasicConstraint::Permille(875),
+            ],
+        );
+
+        assert_eq!(result, [10, 70]);
+    }
+
+    #[test]
+    fn split_percentage_rounding() {
+        assert_eq!(widths(10, [BasicConstraint::Percentage(15)]), [2]);
+        assert_eq!(widths(10, [BasicConstraint::Percentage(14)]), [1]);
+        assert_eq!(widths(10, [BasicConstraint::Permille(150)]), [2]);
+        assert_eq!(widths(10, [BasicConstraint::Permille(149)]), [1]);
+    }
+
+    #[test]
+    fn split_large_area_every_constraint() {
+        let constraints = [
+            (BasicConstraint::Length(40_000), 40_000),
+            (BasicConstraint::Percentage(50), 30_000),
+            (BasicConstraint::Percentage(100), 60_000),
+            (BasicConstraint::Permille(333), 19_980),
+            (BasicConstraint::MinLenGrowthPcnt(10, 25), 15_000),
+            (BasicConstraint::MinLenRemaining(10, 500), 59_500),
+            (BasicConstraint::Ratio(2, 3), 40_000),
+            (BasicConstraint::Fill(3), 60_000),
+            (BasicConstraint::Min(100), 60_000),
+            (BasicConstraint::Max(50_000), 50_000),
+            (BasicConstraint::Between(10, 45_000), 45_000),
+            (BasicConstraint::Content(45_000, 10), 45_000),
+        ];
+
+        for &(constraint, expected) in &constraints {
+            assert_eq!(widths(60_000, [constraint]), [expected], "{constraint:?}");
+        }
+    }
+
+    #[test]
+    fn split_large_area_mixed() {
+        let result = widths(
+            65_535,
+            [
+                BasicConstraint::Percentage(60),
+                BasicConstraint::Permille(100),
+                BasicConstraint::Fill(1),
+                BasicConstraint::Length(1000),
+            ],
+        );
+
+        assert_eq!(result, [39_321, 6554, 18_660, 1000]);
+    }
+
+    #[test]
+    fn split_large_percentage_saturates() {
+        let result = widths(
+            60_000,
+            [BasicConstraint::Percentage(200), BasicConstraint::Fill(1)],
+        );
+
+        assert_eq!(result, [60_000, 0]);
+    }
+
+    #[test]
+    fn split_fill_positions() {
+        let area = Rect::new(2, 0, 10, 1);
+        let [left, right] = SimpleLayout::new(Direction::Horizontal)
+            .split(area, [BasicConstraint::Fill(1), BasicConstraint::Length(3)]);
+
+        assert_eq!(left, Rect::new(2, 0, 7, 1));
+        assert_eq!(right, Rect::new(9, 0, 3, 1));
+    }
+}
@@ -0,0 +1,364 @@
+use super::BasicConstraint;
+use smallvec::SmallVec;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error from parsing a [`BasicConstraint`] or a list of them.
+///
+/// Every variant contains the position of the token that caused the error, as a byte offset from the start of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseConstraintError {
+    /// A constraint in a list was empty, such as the second constraint in `"10,,20"`.
+    Empty { position: usize },
+    /// The kind of constraint wasn't recognized, such as `"size:10"`.
+    UnknownKind { position: usize },
+    /// A number was missing, malformed, or too large to fit.
+    InvalidNumber { position: usize },
+}
+
+impl ParseConstraintError {
+    /// Returns the byte offset of the token that caused the error.
+    #[inline]
+    #[must_use]
+    pub fn position(self) -> usize {
+        match self {
+            Self::Empty { position }
+            | Self::UnknownKind { position }
+            | Self::InvalidNumber { position } => position,
+        }
+    }
+}
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let desc = match self {
+            Self::Empty { .. } => "empty constraint",
+            Self::UnknownKind { .. } => "unknown constraint kind",
+            Self::InvalidNumber { .. } => "invalid number",
+        };
+
+        write!(f, "{} at position {}", desc, self.position())
+    }
+}
+
+impl Error for ParseConstraintError {}
+
+/// Parse a comma-separated list of constraints, such as `"20%, 10, min:5+30%, fill:2"`.
+///
+/// Each constraint uses the same syntax as [`BasicConstraint`]'s `FromStr` implementation.
+/// An input that only contains whitespace results in an empty list.
+#[inline]
+pub fn parse_list(input: &str) -> Result<SmallVec<[BasicConstraint; 4]>, ParseConstraintError> {
+    let mut results = SmallVec::new();
+
+    if input.trim().is_empty() {
+        return Ok(results);
+    }
+
+    let mut offset = 0;
+
+    for token in input.split(',') {
+        results.push(parse_token(token, offset)?);
+        offset += token.len() + 1;
+    }
+
+    Ok(results)
+}
+
+/// Parses a single constraint.
+///
+/// The following syntax is supported, with surrounding whitespace ignored:
+///
+/// | Syntax          | Constraint                   |
+/// |-----------------|------------------------------|
+/// | `10`            | `Length(10)`                 |
+/// | `20%`           | `Percentage(20)`             |
+/// | `12.5%`         | `Permille(125)`              |
+/// | `min:5+30%`     | `MinLenGrowthPcnt(5, 30)`    |
+/// | `min:5+rem:10`  | `MinLenRemaining(5, 10)`     |
+/// | `ratio:1/3`     | `Ratio(1, 3)`                |
+/// | `fill:2`        | `Fill(2)`                    |
+/// | `min:5`         | `Min(5)`                     |
+/// | `max:5`         | `Max(5)`                     |
+/// | `between:2..8`  | `Between(2, 8)`              |
+/// | `content:8/2`   | `Content(8, 2)`              |
+///
+/// The [`Display`](fmt::Display) implementation of [`BasicConstraint`] produces the same syntax, and every constraint it writes can be parsed back.
+/// Values are taken as is, so a percentage greater than 100 or a `Between` whose minimum is larger than its maximum are accepted.
+impl FromStr for BasicConstraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_token(s, 0)
+    }
+}
+
+impl fmt::Display for BasicConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(len) => write!(f, "{len}"),
+            Self::Percentage(pcnt) => write!(f, "{pcnt}%"),
+            Self::Permille(permille) => write!(f, "{}.{}%", permille / 10, permille % 10),
+            Self::MinLenGrowthPcnt(min, pcnt) => write!(f, "min:{min}+{pcnt}%"),
+            Self::MinLenRemaining(min, remaining) => write!(f, "min:{min}+rem:{remaining}"),
+            Self::Ratio(num, den) => write!(f, "ratio:{num}/{den}"),
+            Self::Fill(weight) => write!(f, "fill:{weight}"),
+            Self::Min(min) => write!(f, "min:{min}"),
+            Self::Max(max) => write!(f, "max:{max}"),
+            Self::Between(min, max) => write!(f, "between:{min}..{max}"),
+            Self::Content(preferred, min) => write!(f, "content:{preferred}/{min}"),
+        }
+    }
+}
+
+/// Parse a single constraint from `token`, which starts at byte `offset` of the original input.
+fn parse_token(token: &str, offset: usize) -> Result<BasicConstraint, ParseConstraintError> {
+    let trimmed = token.trim_start();
+    let offset = offset + (token.len() - trimmed.len());
+    let token = trimmed.trim_end();
+
+    if token.is_empty() {
+        return Err(ParseConstraintError::Empty { position: offset });
+    }
+
+    let (kind, value, value_offset) = match token.find(':') {
+        Some(pos) => (&token[..pos], &token[pos + 1..], offset + pos + 1),
+        None => ("", token, offset),
+    };
+
+    match kind {
+        "" => match value.strip_suffix('%') {
+            Some(pcnt) if pcnt.contains('.') => {
+                parse_permille(pcnt, value_offset).map(BasicConstraint::Permille)
+            }
+            Some(pcnt) => parse_number(pcnt, value_offset).map(BasicConstraint::Percentage),
+            None => parse_number(value, value_offset).map(BasicConstraint::Length),
+        },
+        "min" => match value.find('+') {
+            Some(pos) => {
+                let min = parse_number(&value[..pos], value_offset)?;
+
+                let growth = &value[pos + 1..];
+                let growth_offset = value_offset + pos + 1;
+
+                if let Some(remaining) = growth.strip_prefix("rem:") {
+                    let remaining = parse_number(remaining, growth_offset + 4)?;
+                    Ok(BasicConstraint::MinLenRemaining(min, remaining))
+                } else if let Some(pcnt) = growth.strip_suffix('%') {
+                    let pcnt = parse_number(pcnt, growth_offset)?;
+                    Ok(BasicConstraint::MinLenGrowthPcnt(min, pcnt))
+                } else {
+                    Err(ParseConstraintError::InvalidNumber {
+                        position: growth_offset,
+                    })
+                }
+            }
+            None => parse_number(value, value_offset).map(BasicConstraint::Min),
+        },
+        "max" => parse_number(value, value_offset).map(BasicConstraint::Max),
+        "fill" => parse_number(value, value_offset).map(BasicConstraint::Fill),
+        "ratio" => {
+            let (num, den, den_offset) = split_pair(value, "/", value_offset)?;
+            let num = parse_number(num, value_offset)?;
+            let den = parse_number(den, den_offset)?;
+
+            Ok(BasicConstraint::Ratio(num, den))
+        }
+        "between" => {
+            let (min, max, max_offset) = split_pair(value, "..", value_offset)?;
+            let min = parse_number(min, value_offset)?;
+            let max = parse_number(max, max_offset)?;
+
+            Ok(BasicConstraint::Between(min, max))
+        }
+        "content" => {
+            let (preferred, min, min_offset) = split_pair(value, "/", value_offset)?;
+            let preferred = parse_number(preferred, value_offset)?;
+            let min = parse_number(min, min_offset)?;
+
+            Ok(BasicConstraint::Content(preferred, min))
+        }
+        _ => Err(ParseConstraintError::UnknownKind { position: offset }),
+    }
+}
+
+/// Split `value` into two parts around the given `separator`.
+///
+/// Returns both parts, along with the offset of the second part.
+fn split_pair<'a>(
+    value: &'a str,
+    separator: &str,
+    offset: usize,
+) -> Result<(&'a str, &'a str, usize), ParseConstraintError> {
+    match value.find(separator) {
+        Some(pos) => {
+            let second_pos = pos + separator.len();
+            Ok((&value[..pos], &value[second_pos..], offset + second_pos))
+        }
+        None => Err(ParseConstraintError::InvalidNumber {
+            position: offset + value.len(),
+        }),
+    }
+}
+
+fn parse_number(value: &str, offset: usize) -> Result<u16, ParseConstraintError> {
+    let error = ParseConstraintError::InvalidNumber { position: offset };
+
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(error);
+    }
+
+    value.parse().map_err(|_| error)
+}
+
+/// Parse a percentage with exactly one decimal place, such as `12.5`, into a permille.
+fn parse_permille(value: &str, offset: usize) -> Result<u16, ParseConstraintError> {
+    let (whole, tenths, tenths_offset) = split_pair(value, ".", offset)?;
+
+    if tenths.len() != 1 {
+        return Err(ParseConstraintError::InvalidNumber {
+            position: tenths_offset,
+        });
+    }
+
+    let whole = parse_number(whole, offset)?;
+    let tenths = parse_number(tenths, tenths_offset)?;
+
+    whole
+        .checked_mul(10)
+        .and_then(|permille| permille.checked_add(tenths))
+        .ok_or(ParseConstraintError::InvalidNumber { position: offset })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_list, ParseConstraintError};
+    use crate::layout::BasicConstraint;
+
+    #[test]
+    fn parse_every_kind() {
+        let constraints = [
+            ("10", BasicConstraint::Length(10)),
+            ("20%", BasicConstraint::Percentage(20)),
+            ("12.5%", BasicConstraint::Permille(125)),
+            ("0.0%", BasicConstraint::Permille(0)),
+            ("min:5+30%", BasicConstraint::MinLenGrowthPcnt(5, 30)),
+            ("min:5+rem:10", BasicConstraint::MinLenRemaining(5, 10)),
+            ("ratio:1/3", BasicConstraint::Ratio(1, 3)),
+            ("fill:2", BasicConstraint::Fill(2)),
+            ("min:5", BasicConstraint::Min(5)),
+            ("max:5", BasicConstraint::Max(5)),
+            ("between:2..8", BasicConstraint::Between(2, 8)),
+            ("content:8/2", BasicConstraint::Content(8, 2)),
+        ];
+
+        for (input, expected) in &constraints {
+            assert_eq!(input.parse(), Ok(*expected));
+            assert_eq!(expected.to_string(), *input);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let constraints = [
+            BasicConstraint::Length(0),
+            BasicConstraint::Length(u16::MAX),
+            BasicConstraint::Percentage(150),
+            BasicConstraint::Percentage(u16::MAX),
+            BasicConstraint::Permille(1001),
+            BasicConstraint::Permille(u16::MAX),
+            BasicConstraint::MinLenGrowthPcnt(u16::MAX, 250),
+            BasicConstraint::MinLenRemaining(0, u16::MAX),
+            BasicConstraint::Ratio(3, 0),
+            BasicConstraint::Ratio(u16::MAX, u16::MAX),
+            BasicConstraint::Fill(0),
+            BasicConstraint::Min(u16::MAX),
+            BasicConstraint::Max(0),
+            BasicConstraint::Between(8, 2),
+            BasicConstraint::Content(2, 8),
+            BasicConstraint::Content(u16::MAX, 0),
+        ];
+
+        for constraint in &constraints {
+            assert_eq!(constraint.to_string().parse(), Ok(*constraint));
+        }
+
+        let list = constraints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        assert_eq!(parse_list(&list).unwrap().as_slice(), constraints);
+    }
+
+    #[test]
+    fn parse_list_with_whitespace() {
+        let result = parse_list(" 20%, 10 ,min:5+30%,  fill:2 ").unwrap();
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                BasicConstraint::Percentage(20),
+                BasicConstraint::Length(10),
+                BasicConstraint::MinLenGrowthPcnt(5, 30),
+                BasicConstraint::Fill(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_list() {
+        assert_eq!(parse_list("  ").unwrap().as_slice(), []);
+    }
+
+    #[test]
+    fn parse_list_errors() {
+        assert_eq!(
+            parse_list("10,,20"),
+            Err(ParseConstraintError::Empty { position: 3 })
+        );
+
+        assert_eq!(
+            parse_list("10, 20,"),
+            Err(ParseConstraintError::Empty { position: 7 })
+        );
+
+        assert_eq!(
+            parse_list("10, size:5"),
+            Err(ParseConstraintError::UnknownKind { position: 4 })
+        );
+
+        assert_eq!(
+            parse_list("10, min:5+x%"),
+            Err(ParseConstraintError::InvalidNumber { position: 10 })
+        );
+
+        assert_eq!(
+            parse_list("12.25%"),
+            Err(ParseConstraintError::InvalidNumber { position: 3 })
+        );
+
+        assert_eq!(
+            parse_list("6553.6%"),
+            Err(ParseConstraintError::InvalidNumber { position: 0 })
+        );
+
+        assert_eq!(
+            parse_list("70000"),
+            Err(ParseConstraintError::InvalidNumber { position: 0 })
+        );
+
+        assert_eq!(
+            parse_list("between:5"),
+            Err(ParseConstraintError::InvalidNumber { position: 9 })
+        );
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let error = "fill:".parse::<BasicConstraint>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid number at position 5");
+    }
+}
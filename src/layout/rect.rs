@@ -0,0 +1,436 @@
+use tui::layout::{Direction, Rect};
+
+/// Space to remove from each side of a rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Insets {
+    pub top: u16,
+    pub right: u16,
+    pub bottom: u16,
+    pub left: u16,
+}
+
+impl Insets {
+    #[inline]
+    #[must_use]
+    pub const fn new(top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Returns insets with the same amount of space on every side.
+    #[inline]
+    #[must_use]
+    pub const fn all(inset: u16) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Returns insets with `horizontal` space on the left and right sides, and `vertical` space on the top and bottom sides.
+    #[inline]
+    #[must_use]
+    pub const fn symmetric(horizontal: u16, vertical: u16) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
+/// Extensions to modify [`Rect`](https://docs.rs/tui/0.14.0/tui/layout/struct.Rect.html) structs.
+///
+/// The width and height of a returned rect never underflow; they are clamped to zero instead.
+pub trait RectExt: Sized {
+    fn pad(self, padding_x: u16, padding_y: u16) -> Rect;
+    fn pad_horiz(self, padding: u16) -> Rect;
+    fn pad_left(self, padding: u16) -> Rect;
+    fn pad_right(self, padding: u16) -> Rect;
+    fn pad_vert(self, padding: u16) -> Rect;
+    fn pad_top(self, padding: u16) -> Rect;
+    fn pad_bottom(self, padding: u16) -> Rect;
+
+    /// Returns a rect with the given `insets` removed from each side.
+    fn inset(self, insets: Insets) -> Rect;
+
+    /// Returns a rect spanning from the top of the given `rect` down to the number of `lines` specified.
+    fn lines_from_top(self, lines: u16) -> Rect;
+    /// Returns a rect spanning from the bottom of the given `rect` up to the number of `lines` specified.
+    fn lines_from_bottom(self, lines: u16) -> Rect;
+
+    /// Returns a calculated grid position.
+    ///
+    /// The `x` and `y` fields on the `dimensions` Rect should be used to indicate the x and y coordinates on the grid.
+    fn grid_pos(self, dimensions: Rect) -> Rect;
+
+    /// Returns the area shared by both rects.
+    ///
+    /// Unlike [`Rect::intersection`], this returns a zero-sized rect when the rects don't overlap instead of underflowing.
+    fn saturating_intersection(self, other: Rect) -> Rect;
+
+    /// Returns the smallest rect that contains both rects.
+    ///
+    /// Zero-sized rects are ignored, and the right / bottom edges are clamped to `u16::MAX`.
+    fn saturating_union(self, other: Rect) -> Rect;
+
+    /// Returns true if the given point falls within the rect.
+    fn contains_point(self, x: u16, y: u16) -> bool;
+
+    /// Returns the rect moved to be inside of the given `bounds`, shrinking it if it's larger than them.
+    fn clamp_into(self, bounds: Rect) -> Rect;
+
+    /// Returns a rect with the given `width` and `height` centered within this one.
+    ///
+    /// The returned rect is shrunk if it's larger than this one.
+    fn centered(self, width: u16, height: u16) -> Rect;
+
+    /// Split the rect into two at the given `offset`.
+    ///
+    /// With a horizontal `direction`, this returns the left and right halves. With a vertical `direction`, this returns the top and bottom halves.
+    /// An `offset` past the end of the rect results in the second half being empty.
+    fn split_at(self, offset: u16, direction: Direction) -> (Rect, Rect);
+}
+
+impl RectExt for Rect {
+    fn pad(self, padding_x: u16, padding_y: u16) -> Self {
+        Self {
+            x: self.x + padding_x,
+            y: self.y + padding_y,
+            width: self.width.saturating_sub(padding_x * 2),
+            height: self.height.saturating_sub(padding_y * 2),
+        }
+    }
+
+    fn pad_horiz(self, padding: u16) -> Self {
+        Self {
+            x: self.x + padding,
+            width: self.width.saturating_sub(padding * 2),
+            ..self
+        }
+    }
+
+    fn pad_left(self, padding: u16) -> Self {
+        Self {
+            x: self.x + padding,
+            width: self.width.saturating_sub(padding),
+            ..self
+        }
+    }
+
+    fn pad_right(self, padding: u16) -> Self {
+        Self {
+            width: self.width.saturating_sub(padding),
+            ..self
+        }
+    }
+
+    fn pad_vert(self, padding: u16) -> Self {
+        Self {
+            y: self.y + padding,
+            height: self.height.saturating_sub(padding * 2),
+            ..self
+        }
+    }
+
+    fn pad_top(self, padding: u16) -> Self {
+        Self {
+            y: self.y + padding,
+            height: self.height.saturating_sub(padding),
+            ..self
+        }
+    }
+
+    fn pad_bottom(self, padding: u16) -> Self {
+        Self {
+            height: self.height.saturating_sub(padding),
+            ..self
+        }
+    }
+
+    fn inset(self, insets: Insets) -> Self {
+        self.pad_left(insets.left)
+            .pad_right(insets.right)
+            .pad_top(insets.top)
+            .pad_bottom(insets.bottom)
+    }
+
+    fn lines_from_top(self, lines: u16) -> Self {
+        Self {
+            height: lines.min(self.height),
+            ..self
+        }
+    }
+
+    fn lines_from_bottom(self, lines: u16) -> Self {
+        let height = lines.min(self.height);
+
+        Self {
+            y: self.y + (self.height - height),
+            height,
+            ..self
+        }
+    }
+
+    fn grid_pos(self, dimensions: Rect) -> Self {
+        Self {
+            x: self.x + (dimensions.width * dimensions.x),
+            y: self.y + (dimensions.height * dimensions.y),
+            width: dimensions.width,
+            height: dimensions.height,
+        }
+    }
+
+    fn saturating_intersection(self, other: Rect) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+
+        Self {
+            x,
+            y,
+            width: self.right().min(other.right()).saturating_sub(x),
+            height: self.bottom().min(other.bottom()).saturating_sub(y),
+        }
+    }
+
+    fn saturating_union(self, other: Rect) -> Self {
+        if other.width == 0 || other.height == 0 {
+            return self;
+        }
+
+        if self.width == 0 || self.height == 0 {
+            return other;
+        }
+
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Self {
+            x,
+            y,
+            width: self.right().max(other.right()) - x,
+            height: self.bottom().max(other.bottom()) - y,
+        }
+    }
+
+    fn contains_point(self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    fn clamp_into(self, bounds: Rect) -> Self {
+        let width = self.width.min(bounds.width);
+        let height = self.height.min(bounds.height);
+
+        Self {
+            x: self.x.min(bounds.right() - width).max(bounds.x),
+            y: self.y.min(bounds.bottom() - height).max(bounds.y),
+            width,
+            height,
+        }
+    }
+
+    fn centered(self, width: u16, height: u16) -> Self {
+        let width = width.min(self.width);
+        let height = height.min(self.height);
+
+        Self {
+            x: self.x + (self.width - width) / 2,
+            y: self.y + (self.height - height) / 2,
+            width,
+            height,
+        }
+    }
+
+    fn split_at(self, offset: u16, direction: Direction) -> (Self, Self) {
+        match direction {
+            Direction::Horizontal => {
+                let offset = offset.min(self.width);
+
+                let left = Self {
+                    width: offset,
+                    ..self
+                };
+
+                (left, self.pad_left(offset))
+            }
+            Direction::Vertical => {
+                let offset = offset.min(self.height);
+
+                let top = Self {
+                    height: offset,
+                    ..self
+                };
+
+                (top, self.pad_top(offset))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Insets, RectExt};
+    use tui::layout::{Direction, Rect};
+
+    const RECT: Rect = Rect {
+        x: 2,
+        y: 4,
+        width: 10,
+        height: 6,
+    };
+
+    #[test]
+    fn pad_each_side() {
+        assert_eq!(RECT.pad_left(3), Rect::new(5, 4, 7, 6));
+        assert_eq!(RECT.pad_right(3), Rect::new(2, 4, 7, 6));
+        assert_eq!(RECT.pad_top(2), Rect::new(2, 6, 10, 4));
+        assert_eq!(RECT.pad_bottom(2), Rect::new(2, 4, 10, 4));
+    }
+
+    #[test]
+    fn pad_larger_than_rect() {
+        assert_eq!(RECT.pad_right(20).width, 0);
+        assert_eq!(RECT.pad_bottom(20).height, 0);
+        assert_eq!(RECT.pad_top(20).height, 0);
+        assert_eq!(RECT.pad_left(20).width, 0);
+    }
+
+    #[test]
+    fn inset() {
+        assert_eq!(RECT.inset(Insets::new(1, 2, 3, 4)), Rect::new(6, 5, 4, 2));
+
+        assert_eq!(RECT.inset(Insets::all(1)), RECT.pad(1, 1));
+        assert_eq!(RECT.inset(Insets::symmetric(2, 1)), RECT.pad(2, 1));
+        assert_eq!(RECT.inset(Insets::default()), RECT);
+    }
+
+    #[test]
+    fn inset_larger_than_rect() {
+        let rect = RECT.inset(Insets::new(4, 6, 4, 6));
+
+        assert_eq!(rect.width, 0);
+        assert_eq!(rect.height, 0);
+    }
+
+    #[test]
+    fn lines_from_top() {
+        assert_eq!(RECT.lines_from_top(2), Rect::new(2, 4, 10, 2));
+        assert_eq!(RECT.lines_from_top(20), RECT);
+        assert_eq!(RECT.lines_from_top(0).height, 0);
+    }
+
+    #[test]
+    fn lines_from_bottom() {
+        assert_eq!(RECT.lines_from_bottom(2), Rect::new(2, 8, 10, 2));
+        assert_eq!(RECT.lines_from_bottom(4), Rect::new(2, 6, 10, 4));
+        assert_eq!(RECT.lines_from_bottom(6), RECT);
+        assert_eq!(RECT.lines_from_bottom(20), RECT);
+        assert_eq!(RECT.lines_from_bottom(0), Rect::new(2, 10, 10, 0));
+    }
+
+    #[test]
+    fn saturating_intersection() {
+        let other = Rect::new(8, 2, 10, 4);
+        assert_eq!(RECT.saturating_intersection(other), Rect::new(8, 4, 4, 2));
+        assert_eq!(other.saturating_intersection(RECT), Rect::new(8, 4, 4, 2));
+        assert_eq!(RECT.saturating_intersection(RECT), RECT);
+    }
+
+    #[test]
+    fn saturating_intersection_disjoint() {
+        let rect = RECT.saturating_intersection(Rect::new(20, 20, 5, 5));
+
+        assert_eq!(rect.width, 0);
+        assert_eq!(rect.height, 0);
+
+        // Touching edges don't overlap
+        let rect = RECT.saturating_intersection(Rect::new(12, 4, 5, 5));
+        assert_eq!(rect.width, 0);
+    }
+
+    #[test]
+    fn saturating_union() {
+        let other = Rect::new(8, 2, 10, 4);
+        assert_eq!(RECT.saturating_union(other), Rect::new(2, 2, 16, 8));
+        assert_eq!(other.saturating_union(RECT), Rect::new(2, 2, 16, 8));
+    }
+
+    #[test]
+    fn saturating_union_empty() {
+        let empty = Rect::new(50, 50, 0, 3);
+
+        assert_eq!(RECT.saturating_union(empty), RECT);
+        assert_eq!(empty.saturating_union(RECT), RECT);
+    }
+
+    #[test]
+    fn saturating_union_at_max() {
+        let edge = Rect {
+            x: u16::MAX - 2,
+            y: 0,
+            width: 10,
+            height: 1,
+        };
+
+        assert_eq!(RECT.saturating_union(edge).right(), u16::MAX);
+    }
+
+    #[test]
+    fn contains_point() {
+        assert!(RECT.contains_point(2, 4));
+        assert!(RECT.contains_point(11, 9));
+        assert!(!RECT.contains_point(12, 9));
+        assert!(!RECT.contains_point(11, 10));
+        assert!(!RECT.contains_point(1, 4));
+        assert!(!RECT.contains_point(2, 3));
+        assert!(!Rect::new(5, 5, 0, 0).contains_point(5, 5));
+    }
+
+    #[test]
+    fn clamp_into() {
+        let bounds = Rect::new(0, 0, 20, 10);
+
+        assert_eq!(RECT.clamp_into(bounds), RECT);
+        assert_eq!(
+            Rect::new(15, 8, 10, 6).clamp_into(bounds),
+            Rect::new(10, 4, 10, 6)
+        );
+        assert_eq!(Rect::new(0, 0, 30, 30).clamp_into(RECT), RECT);
+        assert_eq!(
+            Rect::new(0, 0, 4, 4).clamp_into(RECT),
+            Rect::new(2, 4, 4, 4)
+        );
+        assert_eq!(
+            Rect::new(30, 30, 4, 4).clamp_into(RECT),
+            Rect::new(8, 6, 4, 4)
+        );
+    }
+
+    #[test]
+    fn centered() {
+        assert_eq!(RECT.centered(4, 2), Rect::new(5, 6, 4, 2));
+        assert_eq!(RECT.centered(5, 3), Rect::new(4, 5, 5, 3));
+        assert_eq!(RECT.centered(20, 20), RECT);
+        assert_eq!(RECT.centered(0, 0), Rect::new(7, 7, 0, 0));
+    }
+
+    #[test]
+    fn split_at() {
+        assert_eq!(
+            RECT.split_at(3, Direction::Horizontal),
+            (Rect::new(2, 4, 3, 6), Rect::new(5, 4, 7, 6))
+        );
+
+        assert_eq!(
+            RECT.split_at(2, Direction::Vertical),
+            (Rect::new(2, 4, 10, 2), Rect::new(2, 6, 10, 4))
+        );
+    }
+
+    #[test]
+    fn split_at_edges() {
+        let (left, right) = RECT.split_at(0, Direction::Horizontal);
+        assert_eq!((left.width, right), (0, RECT));
+
+        let (top, bottom) = RECT.split_at(20, Direction::Vertical);
+        assert_eq!((top, bottom.height), (RECT, 0));
+        assert_eq!(bottom.y, RECT.bottom());
+    }
+}
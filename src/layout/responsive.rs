@@ -0,0 +1,201 @@
+use super::{BasicConstraint, SimpleLayout};
+use tui::layout::{Direction, Rect};
+
+/// The dimension of an area that a [`ResponsiveLayout`] uses to pick a [`Breakpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Width,
+    Height,
+}
+
+/// A layout to use once an area reaches a minimum size.
+#[derive(Clone)]
+pub struct Breakpoint<const N: usize> {
+    min_size: u16,
+    layout: SimpleLayout,
+    constraints: [BasicConstraint; N],
+}
+
+impl<const N: usize> Breakpoint<N> {
+    /// Create a new [`Breakpoint`] that splits areas at least `min_size` characters / lines large in the given `direction`.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        min_size: u16,
+        direction: Direction,
+        constraints: [BasicConstraint; N],
+    ) -> Self {
+        Self {
+            min_size,
+            layout: SimpleLayout::new(direction),
+            constraints,
+        }
+    }
+
+    /// Set the [`SimpleLayout`] used to split areas with this breakpoint.
+    ///
+    /// This can be used to give each breakpoint its own margin or spacing, and replaces the direction given in [`Self::new`].
+    #[inline]
+    #[must_use]
+    pub fn layout(mut self, layout: SimpleLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+}
+
+/// Pick between several layouts based on the size of the area being split.
+///
+/// This is meant for cases like splitting cells side-by-side on wide terminals, and stacking them on narrow ones.
+/// Like [`SimpleLayout`], this never allocates.
+#[derive(Clone)]
+pub struct ResponsiveLayout<const N: usize, const B: usize> {
+    axis: Axis,
+    breakpoints: [Breakpoint<N>; B],
+}
+
+impl<const N: usize, const B: usize> ResponsiveLayout<N, B> {
+    /// Create a new [`ResponsiveLayout`] that picks from the given `breakpoints` based on the `axis` of an area.
+    #[inline]
+    #[must_use]
+    pub const fn new(axis: Axis, breakpoints: [Breakpoint<N>; B]) -> Self {
+        Self { axis, breakpoints }
+    }
+
+    /// Create a new [`ResponsiveLayout`] that picks from the given `breakpoints` based on the width of an area.
+    #[inline]
+    #[must_use]
+    pub const fn by_width(breakpoints: [Breakpoint<N>; B]) -> Self {
+        Self::new(Axis::Width, breakpoints)
+    }
+
+    /// Create a new [`ResponsiveLayout`] that picks from the given `breakpoints` based on the height of an area.
+    #[inline]
+    #[must_use]
+    pub const fn by_height(breakpoints: [Breakpoint<N>; B]) -> Self {
+        Self::new(Axis::Height, breakpoints)
+    }
+
+    /// Returns the index of the breakpoint to use for the given `area`.
+    ///
+    /// This is the breakpoint with the largest minimum size that the `area` reaches.
+    /// If the `area` is smaller than every breakpoint, the breakpoint with the smallest minimum size is used instead.
+    /// Returns `None` if there are no breakpoints.
+    #[inline]
+    #[must_use]
+    pub fn select(&self, area: Rect) -> Option<usize> {
+        let size = match self.axis {
+            Axis::Width => area.width,
+            Axis::Height => area.height,
+        };
+
+        let fitting = self
+            .breakpoints
+            .iter()
+            .enumerate()
+            .filter(|(_, breakpoint)| breakpoint.min_size <= size)
+            .max_by_key(|(_, breakpoint)| breakpoint.min_size);
+
+        let (index, _) = fitting.or_else(|| {
+            self.breakpoints
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, breakpoint)| breakpoint.min_size)
+        })?;
+
+        Some(index)
+    }
+
+    /// Split the given `area` with the breakpoint that fits it best.
+    ///
+    /// See [`Self::select`] for how the breakpoint is picked. If there are no breakpoints, every cell will be empty.
+    #[inline]
+    #[must_use]
+    pub fn split(&self, area: Rect) -> ResponsiveSplit<N> {
+        let Some(index) = self.select(area) else {
+            return ResponsiveSplit {
+                breakpoint: 0,
+                cells: [Rect::default(); N],
+            };
+        };
+
+        let breakpoint = &self.breakpoints[index];
+
+        ResponsiveSplit {
+            breakpoint: index,
+            cells: breakpoint
+                .layout
+                .clone()
+                .split(area, breakpoint.constraints),
+        }
+    }
+}
+
+/// A layout split by a [`ResponsiveLayout`].
+#[derive(Clone)]
+pub struct ResponsiveSplit<const N: usize> {
+    /// The index of the breakpoint that was used to split the area.
+    pub breakpoint: usize,
+    /// The split cells.
+    pub cells: [Rect; N],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoint, ResponsiveLayout};
+    use crate::layout::{BasicConstraint, SimpleLayout};
+    use tui::layout::{Direction, Rect};
+
+    fn test_layout() -> ResponsiveLayout<2, 2> {
+        ResponsiveLayout::by_width([
+            Breakpoint::new(0, Direction::Vertical, [BasicConstraint::Fill(1); 2]),
+            Breakpoint::new(80, Direction::Horizontal, [BasicConstraint::Fill(1); 2]),
+        ])
+    }
+
+    #[test]
+    fn responsive_wide() {
+        let split = test_layout().split(Rect::new(0, 0, 100, 10));
+
+        assert_eq!(split.breakpoint, 1);
+        assert_eq!(
+            split.cells,
+            [Rect::new(0, 0, 50, 10), Rect::new(50, 0, 50, 10)]
+        );
+    }
+
+    #[test]
+    fn responsive_narrow() {
+        let split = test_layout().split(Rect::new(0, 0, 79, 10));
+
+        assert_eq!(split.breakpoint, 0);
+        assert_eq!(
+            split.cells,
+            [Rect::new(0, 0, 79, 5), Rect::new(0, 5, 79, 5)]
+        );
+    }
+
+    #[test]
+    fn responsive_smaller_than_every_breakpoint() {
+        let layout = ResponsiveLayout::by_height([
+            Breakpoint::new(20, Direction::Horizontal, [BasicConstraint::Fill(1)]),
+            Breakpoint::new(10, Direction::Vertical, [BasicConstraint::Fill(1)])
+                .layout(SimpleLayout::new(Direction::Vertical).margin(1)),
+        ]);
+
+        let split = layout.split(Rect::new(0, 0, 10, 5));
+
+        assert_eq!(split.breakpoint, 1);
+        assert_eq!(split.cells, [Rect::new(1, 1, 8, 3)]);
+    }
+
+    #[test]
+    fn responsive_no_breakpoints() {
+        let layout = ResponsiveLayout::<2, 0>::by_width([]);
+
+        assert_eq!(layout.select(Rect::new(0, 0, 10, 10)), None);
+        assert_eq!(
+            layout.split(Rect::new(0, 0, 10, 10)).cells,
+            [Rect::default(); 2]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::layout::RectExt;
+use smallvec::SmallVec;
+use tui::layout::Rect;
+
+/// A widget that can report where its content is drawn, so that terminal positions (such as mouse clicks) can be mapped back to it.
+pub trait HitTest {
+    /// What identifies each piece of the widget's content, such as the index of a row.
+    type Index: Copy;
+
+    /// Returns the position of each piece of content that is visible when the widget is drawn in the given `area`.
+    fn position_map(&self, area: Rect) -> PositionMap<Self::Index>;
+
+    /// Returns the piece of content that is drawn at the given terminal position when the widget is drawn in the given `area`.
+    ///
+    /// If you need to test more than one position, get a [`PositionMap`] with [`HitTest::position_map`] and reuse it instead.
+    #[inline]
+    fn hit_test(&self, area: Rect, x: u16, y: u16) -> Option<Self::Index> {
+        self.position_map(area).hit_test(x, y)
+    }
+}
+
+/// The positions reported by a [`HitTest`] implementation, in the order they're drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionMap<T> {
+    positions: SmallVec<[(T, Rect); 8]>,
+}
+
+impl<T> PositionMap<T>
+where
+    T: Copy,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            positions: SmallVec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, index: T, rect: Rect) {
+        self.positions.push((index, rect));
+    }
+
+    /// Returns an iterator over every piece of content and where it's drawn.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (T, Rect)> + '_ {
+        self.positions.iter().copied()
+    }
+
+    /// Returns where the given piece of content is drawn, if it's visible.
+    #[inline]
+    #[must_use]
+    pub fn rect(&self, index: T) -> Option<Rect>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(other, _)| *other == index)
+            .map(|(_, rect)| rect)
+    }
+
+    /// Returns the piece of content that is drawn at the given terminal position.
+    #[inline]
+    #[must_use]
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<T> {
+        self.iter()
+            .find(|(_, rect)| rect.contains_point(x, y))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the number of pieces of content that are visible.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns `true` if no content is visible.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HitTest;
+    use crate::layout::BasicConstraint;
+    use crate::widgets::{Fragment, SimpleList, SimpleTable, TextFragments};
+    use tui::layout::{Alignment, Rect};
+    use tui::style::Style;
+    use tui::text::Span;
+
+    #[test]
+    fn text_fragment_positions() {
+        let fragments = [
+            Fragment::span("ab"),
+            Fragment::Char('c', Style::default()),
+            Fragment::Line,
+            Fragment::Align(Alignment::Right),
+            Fragment::span("xyz"),
+        ];
+
+        let text = TextFragments::new(&fragments[..]);
+        let positions = text.position_map(Rect::new(2, 1, 6, 2));
+
+        assert_eq!(
+            positions.iter().collect::<Vec<_>>(),
+            vec![
+                (0, Rect::new(2, 1, 2, 1)),
+                (1, Rect::new(4, 1, 1, 1)),
+                (4, Rect::new(5, 2, 3, 1)),
+            ]
+        );
+
+        assert_eq!(positions.hit_test(3, 1), Some(0));
+        assert_eq!(positions.hit_test(4, 1), Some(1));
+        assert_eq!(positions.hit_test(7, 2), Some(4));
+        assert_eq!(positions.hit_test(6, 1), None);
+        assert_eq!(positions.hit_test(4, 2), None);
+    }
+
+    #[test]
+    fn scrolled_text_fragment_positions() {
+        let fragments = [
+            Fragment::span("hidden"),
+            Fragment::Line,
+            Fragment::span("abcdef"),
+            Fragment::span("gh"),
+        ];
+
+        let text = TextFragments::new(&fragments[..]).scroll(1, 2);
+        let positions = text.position_map(Rect::new(0, 0, 4, 2));
+
+        // The second span doesn't fit past the right edge, so it's hidden
+        assert_eq!(
+            positions.iter().collect::<Vec<_>>(),
+            vec![(2, Rect::new(0, 0, 4, 1))]
+        );
+
+        assert_eq!(text.hit_test(Rect::new(0, 0, 4, 2), 3, 0), Some(2));
+        assert_eq!(text.hit_test(Rect::new(0, 0, 4, 2), 0, 1), None);
+    }
+
+    #[test]
+    fn list_positions() {
+        let items = ["one", "two", "three", "four", "five"];
+        let list = SimpleList::new(items.iter().copied().map(Span::raw)).select(3);
+        let area = Rect::new(1, 1, 8, 3);
+        let positions = list.position_map(area);
+
+        // The list scrolls to keep the selected item visible
+        assert_eq!(positions.len(), 3);
+        assert_eq!(positions.rect(0), None);
+        assert_eq!(positions.rect(1), Some(Rect::new(1, 1, 8, 1)));
+        assert_eq!(positions.rect(3), Some(Rect::new(1, 3, 8, 1)));
+
+        assert_eq!(list.hit_test(area, 4, 2), Some(2));
+        assert_eq!(list.hit_test(area, 0, 2), None);
+        assert_eq!(list.hit_test(area, 4, 4), None);
+    }
+
+    #[test]
+    fn short_list_positions() {
+        let items = ["one"];
+        let list = SimpleList::new(items.iter().copied().map(Span::raw));
+
+        assert_eq!(list.position_map(Rect::new(0, 0, 5, 5)).len(), 1);
+        assert!(list.position_map(Rect::new(0, 0, 0, 5)).is_empty());
+    }
+
+    #[test]
+    fn table_positions() {
+        let header = [Span::raw("A"), Span::raw("B")];
+        let data = [
+            [Span::raw("a1"), Span::raw("b1")],
+            [Span::raw("a2"), Span::raw("b2")],
+            [Span::raw("a3"), Span::raw("b3")],
+        ];
+
+        let table = SimpleTable::new(
+            &data,
+            [BasicConstraint::Length(3), BasicConstraint::Length(4)],
+        )
+        .header(&header)
+        .highlight_symbol(Span::raw(">"))
+        .select(2);
+
+        let area = Rect::new(0, 0, 10, 3);
+        let positions = table.position_map(area);
+
+        // The header takes up the first row, the table scrolls to keep the selected row visible,
+        // and each cell starts after the width of the highlight symbol
+        assert_eq!(
+            positions.iter().collect::<Vec<_>>(),
+            vec![
+                ((1, 0), Rect::new(1, 1, 2, 1)),
+                ((1, 1), Rect::new(4, 1, 3, 1)),
+                ((2, 0), Rect::new(1, 2, 2, 1)),
+                ((2, 1), Rect::new(4, 2, 3, 1)),
+            ]
+        );
+
+        assert_eq!(table.hit_test(area, 5, 2), Some((2, 1)));
+        assert_eq!(table.hit_test(area, 1, 1), Some((1, 0)));
+        assert_eq!(table.hit_test(area, 1, 0), None);
+        assert_eq!(table.hit_test(area, 8, 1), None);
+
+        // The space for the highlight symbol isn't part of any cell
+        assert_eq!(table.hit_test(area, 0, 2), None);
+        assert_eq!(table.hit_test(area, 3, 2), None);
+    }
+
+    #[test]
+    fn table_mismatched_header_positions() {
+        let header = [Span::raw("A")];
+        let data = [[Span::raw("a1"), Span::raw("b1")]];
+
+        let table = SimpleTable::with_constraints(
+            &data,
+            &[BasicConstraint::Length(3), BasicConstraint::Length(4)],
+        )
+        .header(&header);
+
+        assert!(table.position_map(Rect::new(0, 0, 10, 3)).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use super::{HitTest, Measure, Measurement, OverflowMode, PositionMap};
 use tui::{buffer::Buffer, layout::Rect, text::Span, widgets::Widget};
 use unicode_width::UnicodeWidthStr;
 
@@ -13,6 +14,7 @@ where
     items: I,
     selected: Option<u16>,
     highlight_symbol: Option<(Span<'a>, Width)>,
+    overflow: OverflowMode,
 }
 
 impl<'a, I> SimpleList<'a, I>
@@ -27,6 +29,7 @@ where
             items,
             selected: None,
             highlight_symbol: None,
+            overflow: OverflowMode::Truncate,
         }
     }
 
@@ -49,6 +52,91 @@ where
         self.highlight_symbol = Some((symbol, len as u16));
         self
     }
+
+    /// Set what happens to items that are too wide to fit. Items are truncated by default.
+    #[inline]
+    #[must_use]
+    pub fn overflow(mut self, overflow: OverflowMode) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns the number of items that are scrolled past to keep the selected item visible within the given `height`.
+    fn scroll_offset(&self, height: u16) -> u16 {
+        match self.selected {
+            Some(selected) if selected >= height => (selected + 1).saturating_sub(height),
+            _ => 0,
+        }
+    }
+}
+
+impl<'a, I> Measure for SimpleList<'a, I>
+where
+    I: IntoIterator<Item = Span<'a>> + Clone,
+{
+    fn measure(&self, max_width: u16) -> Measurement {
+        let symbol_width = self
+            .highlight_symbol
+            .as_ref()
+            .map_or(0, |(_, width)| *width);
+
+        let (item_width, num_items) = self
+            .items
+            .clone()
+            .into_iter()
+            .fold((0, 0_usize), |(width, count), item| {
+                (width.max(item.width() as u16), count + 1)
+            });
+
+        let height = num_items.min(u16::MAX as usize) as u16;
+
+        // Items are truncated to fit, and the list scrolls to keep the selected item visible
+        Measurement {
+            width: symbol_width.saturating_add(item_width).min(max_width),
+            height,
+            min_width: symbol_width.min(max_width),
+            min_height: height.min(1),
+        }
+    }
+}
+
+impl<'a, I> HitTest for SimpleList<'a, I>
+where
+    I: IntoIterator<Item = Span<'a>> + Clone,
+{
+    /// The index of an item.
+    type Index = u16;
+
+    fn position_map(&self, area: Rect) -> PositionMap<u16> {
+        let mut positions = PositionMap::new();
+
+        if area.width == 0 || area.height == 0 {
+            return positions;
+        }
+
+        let y_offset = self.scroll_offset(area.height);
+
+        let num_visible = self
+            .items
+            .clone()
+            .into_iter()
+            .skip(y_offset as usize)
+            .take(area.height as usize)
+            .count() as u16;
+
+        // Each item takes up its entire row, including the space for the highlight symbol
+        for i in 0..num_visible {
+            let rect = Rect {
+                y: area.y + i,
+                height: 1,
+                ..area
+            };
+
+            positions.push(y_offset + i, rect);
+        }
+
+        positions
+    }
 }
 
 impl<'a, I> Widget for SimpleList<'a, I>
@@ -60,10 +148,7 @@ where
             return;
         }
 
-        let y_offset = match self.selected {
-            Some(selected) if selected >= area.height => (selected + 1).saturating_sub(area.height),
-            _ => 0,
-        };
+        let y_offset = self.scroll_offset(area.height);
 
         let x_offset = if let Some((_, width)) = &self.highlight_symbol {
             *width
@@ -93,13 +178,9 @@ where
                 _ => item.style,
             };
 
-            buf.set_stringn(
-                area.x + x_offset,
-                y_pos,
-                item.content,
-                max_width as usize,
-                style,
-            );
+            if let Some(text) = self.overflow.shorten(&item.content, max_width) {
+                text.draw(buf, area.x + x_offset, y_pos, style);
+            }
         }
     }
 }
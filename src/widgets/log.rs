@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
-use super::{Fragment, TextFragments};
+use super::{Fragment, Measure, Measurement, TextFragments};
+use crate::wrap::{WrapMode, Wrapped};
 use tui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
@@ -15,6 +16,7 @@ where
 {
     items: I,
     alignment: Alignment,
+    wrap: Option<WrapMode>,
     _phantom: PhantomData<&'a ()>,
 }
 
@@ -30,6 +32,7 @@ where
         Self {
             items,
             alignment: Alignment::Left,
+            wrap: None,
             _phantom: PhantomData,
         }
     }
@@ -40,6 +43,44 @@ where
         self.alignment = alignment;
         self
     }
+
+    /// Wrap each item with the given `mode` to fit within the width of the log.
+    ///
+    /// Items are wrapped while rendering without allocating, so they can be stored without any `Fragment::Line`'s of their own.
+    #[inline(always)]
+    #[must_use]
+    pub fn wrap(mut self, mode: WrapMode) -> Self {
+        self.wrap = Some(mode);
+        self
+    }
+}
+
+impl<'a, I, Ref> Measure for Log<'a, I, Ref>
+where
+    I: DoubleEndedIterator<Item = Ref> + Clone,
+    Ref: AsRef<[Fragment<'a>]>,
+{
+    fn measure(&self, max_width: u16) -> Measurement {
+        let mut result = Measurement::default();
+
+        for item in self.items.clone() {
+            let item = measure_item(item.as_ref(), self.wrap, max_width);
+
+            result.width = result.width.max(item.width);
+            result.height = result.height.saturating_add(item.height);
+        }
+
+        // Only the newest item has to be visible, as older items are cut off from the top
+        let newest_height = self.items.clone().next_back().map_or(0, |item| {
+            measure_item(item.as_ref(), self.wrap, max_width).height
+        });
+
+        Measurement {
+            min_width: result.width,
+            min_height: newest_height,
+            ..result
+        }
+    }
 }
 
 impl<'a, I, Ref> Widget for Log<'a, I, Ref>
@@ -54,12 +95,14 @@ where
         }
 
         let mut y_pos = area.height;
+        let wrap = self.wrap;
+        let num_lines = move |item: &[Fragment]| measure_item(item, wrap, area.width).height;
 
         let visible_items = {
             let mut total_len = 0;
 
             self.items.into_iter().rev().take_while(move |item| {
-                let len = Fragment::num_lines(item.as_ref());
+                let len = num_lines(item.as_ref());
                 total_len += len;
 
                 total_len <= area.height
@@ -68,20 +111,70 @@ where
 
         for item in visible_items {
             let item = item.as_ref();
-            let num_lines = Fragment::num_lines(item);
+            let num_lines = num_lines(item);
             let item_y_pos = y_pos.saturating_sub(num_lines);
 
-            let fragments = TextFragments::new(item).alignment(self.alignment);
-
             let pos = Rect {
                 y: area.y + item_y_pos,
                 height: num_lines,
                 ..area
             };
 
-            fragments.render(pos, buf);
+            match wrap {
+                Some(mode) => {
+                    let wrapped = Wrapped::new(item, mode, area.width);
+                    TextFragments::new(wrapped)
+                        .alignment(self.alignment)
+                        .render(pos, buf);
+                }
+                None => TextFragments::new(item)
+                    .alignment(self.alignment)
+                    .render(pos, buf),
+            }
 
             y_pos -= num_lines;
         }
     }
 }
+
+/// Measure the given `item` as it would be rendered within the given `width`.
+fn measure_item(item: &[Fragment], wrap: Option<WrapMode>, width: u16) -> Measurement {
+    match wrap {
+        Some(mode) => TextFragments::new(Wrapped::new(item, mode, width)).measure(width),
+        None => TextFragments::new(item).measure(width),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Log;
+    use crate::widgets::Fragment;
+    use crate::wrap::WrapMode;
+    use tui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+    #[test]
+    fn log_wrapped_items() {
+        let items = [
+            vec![Fragment::span("an old message")],
+            vec![Fragment::span("a newer message that wraps")],
+        ];
+
+        let backend = TestBackend::new(12, 5);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                let log = Log::new(items.iter()).wrap(WrapMode::Words);
+                f.render_widget(log, f.size());
+            })
+            .unwrap();
+
+        terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+            "an old      ",
+            "message     ",
+            "a newer     ",
+            "message that",
+            "wraps       ",
+        ]));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::layout::BasicConstraint;
+use tui::layout::Direction;
+
+/// A widget that can report how much space its content needs.
+///
+/// This can be used with [`BasicConstraint::Content`] to size a cell to fit a widget exactly.
+/// See [`Measurement::constraint`] for an easy way to do that.
+pub trait Measure {
+    /// Measure the preferred and minimum size of the widget's content when it can take up at most `max_width` characters.
+    ///
+    /// The reported widths will never be larger than `max_width`.
+    fn measure(&self, max_width: u16) -> Measurement;
+}
+
+/// The size reported by a [`Measure`] implementation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Measurement {
+    /// The width needed to show all of the content.
+    pub width: u16,
+    /// The height needed to show all of the content.
+    pub height: u16,
+    /// The smallest width the content can be shrunk to while still being drawn.
+    pub min_width: u16,
+    /// The smallest height the content can be shrunk to while still being drawn.
+    pub min_height: u16,
+}
+
+impl Measurement {
+    /// Returns a [`BasicConstraint::Content`] with the measured size along the given `direction`.
+    ///
+    /// A [`Direction::Horizontal`] layout uses the width, and a [`Direction::Vertical`] layout uses the height.
+    #[inline]
+    #[must_use]
+    pub fn constraint(self, direction: &Direction) -> BasicConstraint {
+        match direction {
+            Direction::Horizontal => BasicConstraint::Content(self.width, self.min_width),
+            Direction::Vertical => BasicConstraint::Content(self.height, self.min_height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Measure, Measurement};
+    use crate::layout::{BasicConstraint, SimpleLayout};
+    use crate::widgets::{
+        Fragment, Log, OverflowMode, SimpleList, SimpleTable, SimpleText, TextFragments,
+    };
+    use tui::layout::{Direction, Rect};
+    use tui::text::Span;
+
+    #[test]
+    fn measure_text() {
+        let text = SimpleText::new("hello world");
+
+        assert_eq!(
+            text.measure(20),
+            Measurement {
+                width: 11,
+                height: 1,
+                min_width: 11,
+                min_height: 1,
+            }
+        );
+
+        let text = text.overflow(OverflowMode::Truncate);
+
+        assert_eq!(
+            text.measure(5),
+            Measurement {
+                width: 5,
+                height: 1,
+                min_width: 0,
+                min_height: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn measure_fragments() {
+        let fragments = [
+            Fragment::span("first"),
+            Fragment::Line,
+            Fragment::span("second "),
+            Fragment::span("line"),
+            Fragment::Line,
+            Fragment::Line,
+        ];
+
+        let measurement = TextFragments::new(&fragments).measure(20);
+
+        assert_eq!(measurement.width, 11);
+        assert_eq!(measurement.height, 4);
+        assert_eq!(TextFragments::new(&fragments).measure(6).width, 6);
+    }
+
+    #[test]
+    fn measure_list() {
+        let items = [Span::raw("one"), Span::raw("three"), Span::raw("two")];
+        let list = SimpleList::new(items.iter().cloned()).highlight_symbol(Span::raw("> "));
+
+        assert_eq!(
+            list.measure(20),
+            Measurement {
+                width: 7,
+                height: 3,
+                min_width: 2,
+                min_height: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn measure_table() {
+        let header = [Span::raw("Name"), Span::raw("Size")];
+        let data = [
+            [Span::raw("a.txt"), Span::raw("10")],
+            [Span::raw("longer.txt"), Span::raw("20000")],
+        ];
+
+        let table = SimpleTable::new(
+            &data,
+            [BasicConstraint::Length(1), BasicConstraint::Length(1)],
+        )
+        .header(&header)
+        .highlight_symbol(Span::raw(">"));
+
+        assert_eq!(
+            table.measure(40),
+            Measurement {
+                width: 17,
+                height: 3,
+                min_width: 1,
+                min_height: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn measure_log() {
+        let items = [
+            vec![Fragment::span("old entry")],
+            vec![
+                Fragment::span("new"),
+                Fragment::Line,
+                Fragment::span("entry"),
+            ],
+        ];
+
+        let log = Log::new(items.iter());
+
+        assert_eq!(
+            log.measure(20),
+            Measurement {
+                width: 9,
+                height: 3,
+                min_width: 9,
+                min_height: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn measure_content_constraint() {
+        let items = [Span::raw("item"), Span::raw("longer item")];
+        let list = SimpleList::new(items.iter().cloned());
+        let area = Rect::new(0, 0, 30, 5);
+
+        let [sidebar, content] = SimpleLayout::new(Direction::Horizontal).split(
+            area,
+            [
+                list.measure(area.width).constraint(&Direction::Horizontal),
+                BasicConstraint::Fill(1),
+            ],
+        );
+
+        assert_eq!(sidebar, Rect::new(0, 0, 11, 5));
+        assert_eq!(content, Rect::new(11, 0, 19, 5));
+    }
+}
@@ -1,22 +1,40 @@
+pub mod hit_test;
 pub mod list;
 pub mod log;
+pub mod measure;
 pub mod table;
 pub mod text;
 pub mod text_fragments;
 
+pub use hit_test::{HitTest, PositionMap};
 pub use list::SimpleList;
 pub use log::Log;
+pub use measure::{Measure, Measurement};
 pub use table::SimpleTable;
 pub use text::SimpleText;
 pub use text_fragments::TextFragments;
 
-use tui::{style::Style, text::Span};
+use std::borrow::{Borrow, Cow};
+use tui::{buffer::Buffer, layout::Alignment, style::Style, text::Span};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// The default number of columns between each tab stop.
+pub(crate) const TAB_WIDTH: u16 = 8;
 
 #[derive(Debug, Clone)]
 pub enum Fragment<'a> {
     Span(Span<'a>, SpanOptions),
     Char(char, Style),
     Line,
+    /// Set the alignment of the line this is on, and every line after it, in the [`TextFragments`] widget.
+    /// It takes up no space.
+    Align(Alignment),
+    /// Repeat a character to take up the width left over on the line this is on in the [`TextFragments`] widget.
+    /// The space is shared evenly when a line has more than one.
+    ///
+    /// It has a length of 0 everywhere else, including [`Fragment::line_len`], as its width depends on the area it's drawn in.
+    Fill(char, Style),
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -34,9 +52,12 @@ impl<'a> Fragment<'a> {
     #[must_use]
     pub fn total_len<I>(items: I) -> u16
     where
-        I: IntoIterator<Item = &'a Self>,
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
     {
-        items.into_iter().fold(0, |acc, item| acc + item.len())
+        items
+            .into_iter()
+            .fold(0, |acc, item| acc + item.borrow().len())
     }
 
     /// Returns an iterator over all of the given items on the current line.
@@ -53,9 +74,14 @@ impl<'a> Fragment<'a> {
     #[must_use]
     pub fn line_len<I>(items: I) -> u16
     where
-        I: IntoIterator<Item = &'a Self>,
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
     {
-        Self::total_len(Self::line_items(items))
+        Self::total_len(
+            items
+                .into_iter()
+                .take_while(|item| !Self::is_line(item.borrow())),
+        )
     }
 
     /// Returns the total number of lines in the given items.
@@ -63,9 +89,13 @@ impl<'a> Fragment<'a> {
     #[must_use]
     pub fn num_lines<I>(items: I) -> u16
     where
-        I: IntoIterator<Item = &'a Self>,
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
     {
-        1 + items.into_iter().filter(|item| Self::is_line(item)).count() as u16
+        1 + items
+            .into_iter()
+            .filter(|item| Self::is_line(item.borrow()))
+            .count() as u16
     }
 
     #[inline]
@@ -73,8 +103,21 @@ impl<'a> Fragment<'a> {
     pub fn len(&self) -> u16 {
         match self {
             Self::Span(span, _) => span.width() as u16,
-            Self::Char(_, _) => 1,
-            Self::Line => 0,
+            Self::Char(ch, _) => ch.width().unwrap_or(0) as u16,
+            Self::Line | Self::Align(_) | Self::Fill(..) => 0,
+        }
+    }
+
+    /// Returns the width of this fragment when it starts at the given `column` of a line.
+    ///
+    /// Unlike [`Fragment::len`], tabs are expanded to the next tab stop, which are placed every `tab_width` columns.
+    #[inline]
+    #[must_use]
+    pub fn width_at(&self, column: u16, tab_width: u16) -> u16 {
+        match self {
+            Self::Span(span, _) => str_width_at(&span.content, column, tab_width),
+            Self::Char('\t', _) => tab_advance(column, tab_width),
+            fragment => fragment.len(),
         }
     }
 
@@ -87,13 +130,68 @@ impl<'a> PartialEq for Fragment<'a> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Span(fst, _), Self::Span(snd, _)) => fst.content == snd.content,
-            (Self::Char(fst, _), Self::Char(snd, _)) => fst == snd,
+            (Self::Char(fst, _), Self::Char(snd, _)) | (Self::Fill(fst, _), Self::Fill(snd, _)) => {
+                fst == snd
+            }
             (Self::Line, Self::Line) => true,
+            (Self::Align(fst), Self::Align(snd)) => fst == snd,
             _ => false,
         }
     }
 }
 
+/// Returns the number of columns a tab at the given `column` advances to reach the next tab stop.
+pub(crate) fn tab_advance(column: u16, tab_width: u16) -> u16 {
+    match tab_width {
+        0 => 0,
+        tab_width => tab_width - column % tab_width,
+    }
+}
+
+/// Returns the width of the given `text` when it starts at the given `column` of a line, with its tabs expanded.
+pub(crate) fn str_width_at(text: &str, column: u16, tab_width: u16) -> u16 {
+    if !text.contains('\t') {
+        return text.width() as u16;
+    }
+
+    text.split('\t')
+        .enumerate()
+        .fold(0_u16, |width, (i, part)| {
+            let width = if i > 0 {
+                width.saturating_add(tab_advance(column.wrapping_add(width), tab_width))
+            } else {
+                width
+            };
+
+            width.saturating_add(part.width() as u16)
+        })
+}
+
+/// Returns the given `text` with each of its tabs replaced by enough spaces to reach the next tab stop.
+///
+/// The text is only copied when it contains tabs.
+pub(crate) fn expand_tabs(text: &str, column: u16, tab_width: u16) -> Cow<'_, str> {
+    if tab_width == 0 || !text.contains('\t') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut expanded = String::with_capacity(text.len() + usize::from(tab_width));
+    let mut width: u16 = 0;
+
+    for (i, part) in text.split('\t').enumerate() {
+        if i > 0 {
+            let advance = tab_advance(column.wrapping_add(width), tab_width);
+            expanded.extend(std::iter::repeat(' ').take(usize::from(advance)));
+            width = width.saturating_add(advance);
+        }
+
+        expanded.push_str(part);
+        width = width.saturating_add(part.width() as u16);
+    }
+
+    Cow::Owned(expanded)
+}
+
 /// Options for a particular span of text.
 #[derive(Debug, Clone, Copy)]
 pub struct SpanOptions {
@@ -131,6 +229,78 @@ pub enum OverflowMode {
     Hide,
     /// Render only the visible part of the span.
     Truncate,
+    /// Render as much of the start of the span as possible, followed by the given marker.
+    ///
+    /// [`OverflowMode::ellipsis`] creates this mode with the default marker of `…`.
+    Ellipsis(&'static str),
+    /// Render as much of the start and end of the span as possible, with the given marker between them.
+    /// This is useful for file paths, where the end is usually the most important part.
+    ///
+    /// [`OverflowMode::middle_ellipsis`] creates this mode with the default marker of `…`.
+    MiddleEllipsis(&'static str),
+}
+
+impl OverflowMode {
+    /// The marker used by [`OverflowMode::ellipsis`] and [`OverflowMode::middle_ellipsis`].
+    pub const ELLIPSIS: &'static str = "…";
+
+    #[inline]
+    #[must_use]
+    pub const fn ellipsis() -> Self {
+        Self::Ellipsis(Self::ELLIPSIS)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn middle_ellipsis() -> Self {
+        Self::MiddleEllipsis(Self::ELLIPSIS)
+    }
+
+    /// Returns the parts of the given `content` that should be drawn to fit it within `max_width`, or `None` if it shouldn't be drawn at all.
+    ///
+    /// Content is only ever cut between graphemes. When a marker doesn't fit by itself, the content is truncated instead.
+    pub(crate) fn shorten(self, content: &str, max_width: u16) -> Option<Shortened<'_>> {
+        let width = content.width();
+
+        if width <= usize::from(max_width) {
+            return Some(Shortened {
+                head: content,
+                marker: "",
+                tail: "",
+                width: width as u16,
+            });
+        }
+
+        let marker = match self {
+            Self::Hide => return None,
+            Self::Truncate => "",
+            Self::Ellipsis(marker) | Self::MiddleEllipsis(marker) => marker,
+        };
+
+        let marker_width = marker.width();
+
+        let (marker, budget) = if marker_width <= usize::from(max_width) {
+            (marker, max_width - marker_width as u16)
+        } else {
+            ("", max_width)
+        };
+
+        let (head, tail) = match self {
+            Self::MiddleEllipsis(_) if !marker.is_empty() => {
+                let (head, head_width) = take_width(content, budget / 2);
+                let (tail, _) = take_width_rev(&content[head.len()..], budget - head_width);
+                (head, tail)
+            }
+            _ => (take_width(content, budget).0, ""),
+        };
+
+        Some(Shortened {
+            head,
+            marker,
+            tail,
+            width: (head.width() + marker.width() + tail.width()) as u16,
+        })
+    }
 }
 
 impl Default for OverflowMode {
@@ -138,3 +308,171 @@ impl Default for OverflowMode {
         Self::Hide
     }
 }
+
+/// The visible parts of a span after its [`OverflowMode`] has been applied.
+pub(crate) struct Shortened<'a> {
+    head: &'a str,
+    marker: &'static str,
+    tail: &'a str,
+    width: u16,
+}
+
+impl Shortened<'_> {
+    /// Returns the total width of every part.
+    pub(crate) fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns every part in the order they're drawn.
+    pub(crate) fn parts(&self) -> [&str; 3] {
+        [self.head, self.marker, self.tail]
+    }
+
+    /// Draw every part at the given `x` and `y` position with the given `style`.
+    pub(crate) fn draw(&self, buf: &mut Buffer, x: u16, y: u16, style: Style) {
+        let mut x = x;
+
+        for part in [self.head, self.marker, self.tail] {
+            if !part.is_empty() {
+                x = buf
+                    .set_stringn(x, y, part, usize::from(self.width), style)
+                    .0;
+            }
+        }
+    }
+}
+
+/// Returns the longest start of the given `content` that fits within `width`, along with its actual width.
+fn take_width(content: &str, width: u16) -> (&str, u16) {
+    let mut total: u16 = 0;
+
+    for (pos, grapheme) in content.grapheme_indices(true) {
+        let grapheme_width = grapheme.width() as u16;
+
+        if total + grapheme_width > width {
+            return (&content[..pos], total);
+        }
+
+        total += grapheme_width;
+    }
+
+    (content, total)
+}
+
+/// Returns the longest end of the given `content` that fits within `width`, along with its actual width.
+fn take_width_rev(content: &str, width: u16) -> (&str, u16) {
+    let mut total: u16 = 0;
+
+    for (pos, grapheme) in content.grapheme_indices(true).rev() {
+        let grapheme_width = grapheme.width() as u16;
+
+        if total + grapheme_width > width {
+            return (&content[pos + grapheme.len()..], total);
+        }
+
+        total += grapheme_width;
+    }
+
+    (content, total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_tabs, Fragment, OverflowMode};
+    use tui::style::Style;
+
+    #[test]
+    fn width_at_tabs() {
+        assert_eq!(Fragment::span("a\tb").width_at(0, 4), 5);
+        assert_eq!(Fragment::span("a\tb").width_at(2, 4), 3);
+        assert_eq!(Fragment::span("a\t\tb").width_at(0, 4), 9);
+        assert_eq!(Fragment::span("a\tb").width_at(0, 0), 2);
+        assert_eq!(Fragment::span("你\tb").width_at(3, 4), 6);
+        assert_eq!(Fragment::Char('\t', Style::default()).width_at(5, 4), 3);
+        assert_eq!(Fragment::Char('\t', Style::default()).width_at(8, 4), 4);
+    }
+
+    #[test]
+    fn expand_tabs_to_stops() {
+        assert_eq!(expand_tabs("a\tb", 0, 4), "a   b");
+        assert_eq!(expand_tabs("a\tb", 1, 4), "a  b");
+        assert_eq!(expand_tabs("\t\tb", 0, 2), "    b");
+        assert_eq!(expand_tabs("a\tb", 0, 0), "a\tb");
+        assert!(matches!(
+            expand_tabs("ab", 0, 4),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    fn shorten(mode: OverflowMode, content: &str, max_width: u16) -> Option<String> {
+        mode.shorten(content, max_width)
+            .map(|text| [text.head, text.marker, text.tail].concat())
+    }
+
+    #[test]
+    fn shorten_fits() {
+        for &mode in &[
+            OverflowMode::Hide,
+            OverflowMode::Truncate,
+            OverflowMode::ellipsis(),
+            OverflowMode::middle_ellipsis(),
+        ] {
+            assert_eq!(shorten(mode, "hello", 5).as_deref(), Some("hello"));
+        }
+    }
+
+    #[test]
+    fn shorten_hide_truncate() {
+        assert_eq!(shorten(OverflowMode::Hide, "hello", 4), None);
+        assert_eq!(
+            shorten(OverflowMode::Truncate, "hello", 4).as_deref(),
+            Some("hell")
+        );
+    }
+
+    #[test]
+    fn shorten_ellipsis() {
+        let mode = OverflowMode::ellipsis();
+
+        assert_eq!(shorten(mode, "hello world", 6).as_deref(), Some("hello…"));
+        assert_eq!(shorten(mode, "hello", 1).as_deref(), Some("…"));
+        assert_eq!(shorten(mode, "hello", 0).as_deref(), Some(""));
+
+        let mode = OverflowMode::Ellipsis("...");
+
+        assert_eq!(shorten(mode, "hello world", 8).as_deref(), Some("hello..."));
+        assert_eq!(shorten(mode, "hello world", 2).as_deref(), Some("he"));
+    }
+
+    #[test]
+    fn shorten_ellipsis_wide() {
+        let mode = OverflowMode::ellipsis();
+        let text = mode.shorten("你好世界", 6).unwrap();
+
+        assert_eq!([text.head, text.marker].concat(), "你好…");
+        assert_eq!(text.width(), 5);
+
+        assert_eq!(
+            shorten(mode, "e\u{301}e\u{301}e\u{301}", 2).as_deref(),
+            Some("e\u{301}…")
+        );
+    }
+
+    #[test]
+    fn shorten_middle_ellipsis() {
+        let mode = OverflowMode::middle_ellipsis();
+
+        assert_eq!(
+            shorten(mode, "/home/user/file.txt", 11).as_deref(),
+            Some("/home…e.txt")
+        );
+
+        assert_eq!(
+            shorten(mode, "/home/user/file.txt", 12).as_deref(),
+            Some("/home…le.txt")
+        );
+
+        assert_eq!(shorten(mode, "你好世界", 6).as_deref(), Some("你…界"));
+        assert_eq!(shorten(mode, "abc", 1).as_deref(), Some("…"));
+    }
+}
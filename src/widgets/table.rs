@@ -1,4 +1,6 @@
+use super::{HitTest, Measure, Measurement, OverflowMode, PositionMap};
 use crate::layout::{BasicConstraint, SimpleLayout};
+use smallvec::{smallvec, SmallVec};
 use tui::{
     buffer::Buffer,
     layout::{Direction, Rect},
@@ -10,19 +12,23 @@ use unicode_width::UnicodeWidthStr;
 type Width = u16;
 
 /// A table widget similar to [`tui::widget::Table`](https://docs.rs/tui/0.14.0/tui/widgets/struct.Table.html).
-pub struct SimpleTable<'a, I, Ref, const N: usize>
+///
+/// The column constraints `C` can either be an array, which is solved without allocating, or a slice that is only known at runtime.
+pub struct SimpleTable<'a, I, Ref, C>
 where
     I: IntoIterator<Item = Ref>,
     Ref: AsRef<[Span<'a>]>,
+    C: ColumnLayout,
 {
     data: I,
-    layout: [BasicConstraint; N],
+    layout: C,
     header: Option<&'a [Span<'a>]>,
     selected: Option<u16>,
     highlight_symbol: Option<(Span<'a>, Width)>,
+    overflow: OverflowMode,
 }
 
-impl<'a, I, Ref, const N: usize> SimpleTable<'a, I, Ref, N>
+impl<'a, I, Ref, const N: usize> SimpleTable<'a, I, Ref, [BasicConstraint; N]>
 where
     I: IntoIterator<Item = Ref>,
     Ref: AsRef<[Span<'a>]>,
@@ -30,12 +36,38 @@ where
     /// Returns a new [`SimpleTable`] with the given `data` and column `layout`.
     #[inline]
     pub fn new(data: I, layout: [BasicConstraint; N]) -> Self {
+        Self::with_layout(data, layout)
+    }
+}
+
+impl<'a, I, Ref> SimpleTable<'a, I, Ref, &'a [BasicConstraint]>
+where
+    I: IntoIterator<Item = Ref>,
+    Ref: AsRef<[Span<'a>]>,
+{
+    /// Returns a new [`SimpleTable`] with the given `data` and a column `layout` that is only known at runtime.
+    ///
+    /// The columns are solved exactly the same way as they are with [`SimpleTable::new`].
+    #[inline]
+    pub fn with_constraints(data: I, layout: &'a [BasicConstraint]) -> Self {
+        Self::with_layout(data, layout)
+    }
+}
+
+impl<'a, I, Ref, C> SimpleTable<'a, I, Ref, C>
+where
+    I: IntoIterator<Item = Ref>,
+    Ref: AsRef<[Span<'a>]>,
+    C: ColumnLayout,
+{
+    fn with_layout(data: I, layout: C) -> Self {
         Self {
             data,
             layout,
             header: None,
             selected: None,
             highlight_symbol: None,
+            overflow: OverflowMode::Truncate,
         }
     }
 
@@ -60,19 +92,207 @@ where
         self.highlight_symbol = Some((symbol, len as u16));
         self
     }
+
+    /// Set what happens to cells that are too wide to fit in their column. Cells are truncated by default.
+    #[inline]
+    #[must_use]
+    pub fn overflow(mut self, overflow: OverflowMode) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns the number of rows that are scrolled past to keep the selected row visible within the given `height`.
+    fn row_offset(&self, height: u16, header_offset: u16) -> u16 {
+        match self.selected {
+            Some(selected) if selected >= height.saturating_sub(header_offset) => {
+                header_offset + (selected + 1).saturating_sub(height)
+            }
+            _ => 0,
+        }
+    }
 }
 
-impl<'a, I, Ref, const N: usize> Widget for SimpleTable<'a, I, Ref, N>
+/// The column constraints of a [`SimpleTable`].
+pub trait ColumnLayout {
+    /// The rect of each column.
+    type Rects: AsRef<[Rect]>;
+
+    /// Returns the number of columns.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no columns.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Split the given `area` into a rect for each column.
+    fn split(&self, area: Rect) -> Self::Rects;
+}
+
+impl<const N: usize> ColumnLayout for [BasicConstraint; N] {
+    type Rects = [Rect; N];
+
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn split(&self, area: Rect) -> [Rect; N] {
+        SimpleLayout::new(Direction::Horizontal).split(area, *self)
+    }
+}
+
+impl ColumnLayout for &[BasicConstraint] {
+    type Rects = SmallVec<[Rect; 4]>;
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[BasicConstraint]>::len(self)
+    }
+
+    #[inline]
+    fn split(&self, area: Rect) -> SmallVec<[Rect; 4]> {
+        SimpleLayout::new(Direction::Horizontal).split_slice(area, self)
+    }
+}
+
+impl<'a, I, Ref, C> Measure for SimpleTable<'a, I, Ref, C>
+where
+    I: IntoIterator<Item = Ref> + Clone,
+    Ref: AsRef<[Span<'a>]>,
+    C: ColumnLayout,
+{
+    fn measure(&self, max_width: u16) -> Measurement {
+        let num_columns = self.layout.len();
+        let offset_x = self
+            .highlight_symbol
+            .as_ref()
+            .map_or(0, |(_, width)| *width);
+
+        let mut column_widths: SmallVec<[u16; 4]> = smallvec![0; num_columns];
+
+        let mut measure_row = |row: &[Span]| {
+            for (width, column) in column_widths.iter_mut().zip(row) {
+                *width = (*width).max(column.width() as u16);
+            }
+        };
+
+        let header_height: u16 = match self.header {
+            Some(header) if header.len() == num_columns => {
+                measure_row(header);
+                1
+            }
+            Some(_) => return Measurement::default(),
+            None => 0,
+        };
+
+        let mut num_rows: u16 = 0;
+
+        // Rendering stops at the first row that doesn't match the number of columns
+        for row in self.data.clone() {
+            let row = row.as_ref();
+
+            if row.len() != num_columns {
+                break;
+            }
+
+            measure_row(row);
+            num_rows = num_rows.saturating_add(1);
+        }
+
+        // Every column is offset by the width of the highlight symbol
+        let width = column_widths.iter().fold(0_u16, |acc, &width| {
+            acc.saturating_add(width).saturating_add(offset_x)
+        });
+
+        Measurement {
+            width: width.min(max_width),
+            height: header_height.saturating_add(num_rows),
+            min_width: offset_x.min(max_width),
+            min_height: header_height + num_rows.min(1),
+        }
+    }
+}
+
+impl<'a, I, Ref, C> HitTest for SimpleTable<'a, I, Ref, C>
+where
+    I: IntoIterator<Item = Ref> + Clone,
+    Ref: AsRef<[Span<'a>]>,
+    C: ColumnLayout,
+{
+    /// The row and column index of a cell. The header isn't included.
+    type Index = (u16, u16);
+
+    fn position_map(&self, area: Rect) -> PositionMap<(u16, u16)> {
+        let mut positions = PositionMap::new();
+
+        if area.width == 0 || area.height == 0 {
+            return positions;
+        }
+
+        let layout = self.layout.split(area);
+        let layout = layout.as_ref();
+
+        let header_offset = match self.header {
+            Some(columns) if columns.len() != layout.len() => return positions,
+            Some(_) => 1,
+            None => 0,
+        };
+
+        let offset_x = self
+            .highlight_symbol
+            .as_ref()
+            .map_or(0, |(_, width)| *width);
+
+        // Rendering stops at the first column that has no room left after the highlight symbol
+        let num_columns = layout.iter().take_while(|pos| pos.width > offset_x).count();
+
+        let item_offset = self.row_offset(area.height, header_offset);
+
+        for (row_index, row) in self
+            .data
+            .clone()
+            .into_iter()
+            .skip(item_offset as usize)
+            .enumerate()
+        {
+            let row_index = row_index as u16;
+
+            if row_index + header_offset >= area.height || layout.len() != row.as_ref().len() {
+                break;
+            }
+
+            for (column_index, pos) in layout[..num_columns].iter().enumerate() {
+                // Cells are drawn after the space for the highlight symbol
+                let rect = Rect {
+                    x: pos.x + offset_x,
+                    y: area.y + header_offset + row_index,
+                    width: pos.width - offset_x,
+                    height: 1,
+                };
+
+                positions.push((item_offset + row_index, column_index as u16), rect);
+            }
+        }
+
+        positions
+    }
+}
+
+impl<'a, I, Ref, C> Widget for SimpleTable<'a, I, Ref, C>
 where
     I: IntoIterator<Item = Ref>,
     Ref: AsRef<[Span<'a>]>,
+    C: ColumnLayout,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if area.width == 0 || area.height == 0 {
             return;
         }
 
-        let layout = SimpleLayout::new(Direction::Horizontal).split(area, self.layout);
+        let layout = self.layout.split(area);
+        let layout = layout.as_ref();
 
         let offset_x = self
             .highlight_symbol
@@ -92,7 +312,9 @@ where
                     break;
                 }
 
-                buf.set_span(offset_x + pos.x, pos.y, column, max_width);
+                if let Some(text) = self.overflow.shorten(&column.content, max_width) {
+                    text.draw(buf, offset_x + pos.x, pos.y, column.style);
+                }
             }
 
             1
@@ -100,12 +322,7 @@ where
             0
         };
 
-        let item_offset = match self.selected {
-            Some(selected) if selected >= area.height.saturating_sub(header_offset) => {
-                header_offset + (selected + 1).saturating_sub(area.height)
-            }
-            _ => 0,
-        };
+        let item_offset = self.row_offset(area.height, header_offset);
 
         let mut offset_y = header_offset;
 
@@ -127,7 +344,7 @@ where
 
             for (column_index, column) in row.iter().enumerate() {
                 let pos = layout[column_index];
-                let max_width = pos.width.saturating_sub(offset_x) as usize;
+                let max_width = pos.width.saturating_sub(offset_x);
 
                 if max_width == 0 {
                     break;
@@ -138,13 +355,9 @@ where
                     _ => column.style,
                 };
 
-                buf.set_stringn(
-                    offset_x + pos.x,
-                    offset_y + pos.y,
-                    &column.content,
-                    max_width,
-                    style,
-                );
+                if let Some(text) = self.overflow.shorten(&column.content, max_width) {
+                    text.draw(buf, offset_x + pos.x, offset_y + pos.y, style);
+                }
             }
 
             offset_y += 1;
@@ -154,18 +367,20 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::SimpleTable;
+    use super::{ColumnLayout, SimpleTable};
     use crate::layout::BasicConstraint;
+    use crate::widgets::OverflowMode;
     use tui::{backend::TestBackend, buffer::Buffer, layout::Rect, text::Span, Terminal};
 
-    fn test_table<'a, I, Ref, const N: usize>(
-        table: SimpleTable<'a, I, Ref, N>,
+    fn test_table<'a, I, Ref, C>(
+        table: SimpleTable<'a, I, Ref, C>,
         width: u16,
         height: u16,
         expected: Buffer,
     ) where
         I: IntoIterator<Item = Ref>,
         Ref: AsRef<[Span<'a>]>,
+        C: ColumnLayout,
     {
         let backend = TestBackend::new(width, height);
         let mut terminal = Terminal::new(backend).unwrap();
@@ -182,7 +397,7 @@ mod tests {
         data: I,
         header: &'a [Span<'a>],
         select: u16,
-    ) -> SimpleTable<'a, I, Ref, 2>
+    ) -> SimpleTable<'a, I, Ref, [BasicConstraint; 2]>
     where
         I: IntoIterator<Item = Ref>,
         Ref: AsRef<[Span<'a>]>,
@@ -274,6 +489,24 @@ mod tests {
         test_table(table, 10, 1, expected);
     }
 
+    #[test]
+    fn table_ellipsis_overflow() {
+        let data = [
+            [Span::raw("Short"), Span::raw("/a/long/path")],
+            [Span::raw("Too long"), Span::raw("/short")],
+        ];
+
+        let table = SimpleTable::new(
+            &data,
+            [BasicConstraint::Length(6), BasicConstraint::Length(7)],
+        )
+        .overflow(OverflowMode::middle_ellipsis());
+
+        let expected = Buffer::with_lines(vec!["Short /a/…ath", "To…ong/short"]);
+
+        test_table(table, 13, 2, expected);
+    }
+
     #[test]
     fn basic_multiline_table() {
         let data = test_data();
@@ -446,6 +679,29 @@ mod tests {
         test_table(table, 25, 6, expected);
     }
 
+    #[test]
+    fn table_with_runtime_constraints() {
+        let data = test_data();
+        let header = test_header();
+        let constraints = [
+            BasicConstraint::Percentage(50),
+            BasicConstraint::Percentage(50),
+        ];
+
+        let table = SimpleTable::with_constraints(&data, &constraints).header(&header);
+
+        let expected = Buffer::with_lines(vec![
+            "Left Header Right Header",
+            "Left1       Right1",
+            "Left2       Right2",
+            "Left3       Right3",
+            "Left4       Right4",
+            "Left5       Right5",
+        ]);
+
+        test_table(table, 24, 6, expected);
+    }
+
     #[test]
     fn table_right_header_cutoff() {
         let data = test_data();
@@ -5,8 +5,9 @@ use tui::{
     text::Span,
     widgets::Widget,
 };
+use unicode_width::UnicodeWidthStr;
 
-use super::OverflowMode;
+use super::{Measure, Measurement, OverflowMode};
 
 /// This widget provides a fast way to draw a single line of text with a fixed style.
 ///
@@ -46,30 +47,39 @@ impl<'a> SimpleText<'a> {
     }
 }
 
+impl Measure for SimpleText<'_> {
+    fn measure(&self, max_width: u16) -> Measurement {
+        let width = (self.span.width() as u16).min(max_width);
+
+        // Hidden text isn't drawn at all when it doesn't fit, so it can't be shrunk
+        let min_width = match self.overflow {
+            OverflowMode::Hide => width,
+            OverflowMode::Truncate => 0,
+            OverflowMode::Ellipsis(marker) | OverflowMode::MiddleEllipsis(marker) => {
+                (marker.width() as u16).min(width)
+            }
+        };
+
+        Measurement {
+            width,
+            height: 1,
+            min_width,
+            min_height: 1,
+        }
+    }
+}
+
 impl<'a> Widget for SimpleText<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if area.width == 0 || area.height == 0 {
             return;
         }
 
-        let len = self.span.width() as u16;
-        let offset = alignment_offset(self.alignment, area.width, len);
-
-        let max_width = if area.width < len {
-            match self.overflow {
-                OverflowMode::Hide => return,
-                OverflowMode::Truncate => area.width,
-            }
-        } else {
-            len
+        let Some(text) = self.overflow.shorten(&self.span.content, area.width) else {
+            return;
         };
 
-        buf.set_stringn(
-            area.x + offset,
-            area.y,
-            self.span.content,
-            max_width as usize,
-            self.span.style,
-        );
+        let offset = alignment_offset(self.alignment, area.width, text.width());
+        text.draw(buf, area.x + offset, area.y, self.span.style);
     }
 }
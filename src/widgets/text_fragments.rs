@@ -1,34 +1,55 @@
-use super::{Fragment, OverflowMode};
+use super::{
+    expand_tabs, tab_advance, Fragment, HitTest, Measure, Measurement, PositionMap, TAB_WIDTH,
+};
 use crate::alignment_offset;
+use std::{borrow::Borrow, marker::PhantomData};
 use tui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
+    style::Style,
     text::Span,
     widgets::Widget,
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Draw fragments of text with different styles across multiple lines.
 ///
 /// This serves as an alternative for `tui::widget::Paragraph`.
-/// It is meant to be used for simple text layouts that don't need scrolling.
+/// It is meant to be used for simple text layouts, which can be scrolled with [`TextFragments::scroll`].
+///
+/// The fragments can be given as a slice, or as any other cloneable iterator of fragments, such as [`Wrapped`](crate::wrap::Wrapped).
 ///
 /// If you only need to draw a single line of text with one style, consider using [`SimpleText`][`crate::widgets::simple_text::SimpleText`] instead.
-pub struct TextFragments<'a> {
-    items: &'a [Fragment<'a>],
+pub struct TextFragments<'a, I = &'a [Fragment<'a>]> {
+    items: I,
     alignment: Alignment,
+    justify: bool,
+    tab_width: u16,
+    scroll: (u16, u16),
+    _phantom: PhantomData<&'a ()>,
 }
 
-impl<'a> TextFragments<'a> {
+impl<'a, I> TextFragments<'a, I>
+where
+    I: IntoIterator + Clone,
+    I::IntoIter: Clone,
+    I::Item: Borrow<Fragment<'a>>,
+{
     #[inline]
     #[must_use]
-    pub fn new(items: &'a [Fragment<'a>]) -> Self {
+    pub fn new(items: I) -> Self {
         Self {
             items,
             alignment: Alignment::Left,
+            justify: false,
+            tab_width: TAB_WIDTH,
+            scroll: (0, 0),
+            _phantom: PhantomData,
         }
     }
 
+    /// Set the alignment of each line, until it's changed by a [`Fragment::Align`].
     #[inline(always)]
     #[allow(clippy::must_use_candidate)]
     pub fn alignment(mut self, alignment: Alignment) -> Self {
@@ -36,85 +57,729 @@ impl<'a> TextFragments<'a> {
         self
     }
 
-    fn can_draw_at_x(area: Rect, x: u16) -> bool {
-        x <= area.width
+    /// Set the number of columns between each tab stop, which defaults to 8.
+    ///
+    /// Tabs are drawn as spaces that reach the next tab stop from the column they start at. They aren't drawn at all when set to 0.
+    #[inline(always)]
+    #[must_use]
+    pub fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
     }
 
-    fn can_draw_at_y(area: Rect, y: u16) -> bool {
-        y < area.height
+    /// Spread the space left over on each line between its words, so that it fills the width of the area.
+    ///
+    /// Only left aligned lines are justified, and the last line of each paragraph is left as is.
+    /// Paragraphs end before an empty line, or at the very last line. Lines with tabs aren't justified, as it would move their tab stops.
+    #[inline(always)]
+    #[must_use]
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Skip the first `y` lines and `x` columns of the fragments.
+    ///
+    /// Lines are aligned, justified and filled against the width of the area before they're scrolled.
+    /// Wide characters that are only partly scrolled into view are drawn as spaces.
+    #[inline(always)]
+    #[must_use]
+    pub fn scroll(mut self, y: u16, x: u16) -> Self {
+        self.scroll = (y, x);
+        self
     }
-}
 
-impl<'a> Widget for TextFragments<'a> {
+    /// Returns the total number of lines in the fragments, which can be used to keep the vertical scroll offset in bounds.
     #[inline]
-    fn render(self, area: Rect, buf: &mut Buffer) {
+    #[must_use]
+    pub fn content_height(&self) -> u16 {
+        Fragment::num_lines(self.items.clone())
+    }
+
+    /// Returns a summary of the first line in the given `items`.
+    fn scan_line<L>(items: L, tab_width: u16) -> LineInfo
+    where
+        L: IntoIterator,
+        L::Item: Borrow<Fragment<'a>>,
+    {
+        let mut items = items.into_iter();
+        let mut line = LineInfo::default();
+        let mut gaps = Justify::default();
+
+        line.paragraph_end = loop {
+            let Some(item) = items.next() else {
+                break true;
+            };
+
+            match item.borrow() {
+                Fragment::Line => {
+                    break items
+                        .next()
+                        .map_or(true, |item| matches!(item.borrow(), Fragment::Line));
+                }
+                Fragment::Align(alignment) => line.alignment = Some(*alignment),
+                Fragment::Fill(..) => line.fills = line.fills.saturating_add(1),
+                Fragment::Span(span, _) => {
+                    line.has_tabs |= span.content.contains('\t');
+                    span.content.chars().for_each(|ch| {
+                        gaps.next(ch);
+                    });
+                }
+                Fragment::Char(ch, _) => {
+                    line.has_tabs |= *ch == '\t';
+                    gaps.next(*ch);
+                }
+            }
+
+            line.width = line
+                .width
+                .saturating_add(item.borrow().width_at(line.width, tab_width));
+        };
+
+        line.gaps = gaps.spread.index;
+        line
+    }
+
+    /// Lay out the fragments in the given `area`, and call `visit` with the index and position of each fragment that's visible.
+    ///
+    /// The fragments are only drawn when given a `buf`.
+    fn draw<F>(&self, area: Rect, mut buf: Option<&mut Buffer>, mut visit: F)
+    where
+        F: FnMut(usize, Rect),
+    {
         if area.width == 0 || area.height == 0 {
             return;
         }
 
-        let mut item_offset = 0;
+        let mut items = self.items.clone().into_iter().enumerate();
+        let mut alignment = self.alignment;
+        let (scroll_y, scroll_x) = self.scroll;
+        let right = scroll_x.saturating_add(area.width);
         let mut offset_y = 0;
 
-        while item_offset <= self.items.len() {
-            let line_items = {
-                let slice = &self.items[item_offset..];
+        for _ in 0..scroll_y {
+            if !Self::skip_line(items.by_ref().map(|(_, item)| item), &mut alignment) {
+                return;
+            }
+        }
+
+        loop {
+            let line = Self::scan_line(items.clone().map(|(_, item)| item), self.tab_width);
+            alignment = line.alignment.unwrap_or(alignment);
 
-                let next_line_pos = slice
-                    .iter()
-                    .position(Fragment::is_line)
-                    .unwrap_or(slice.len());
+            let line_x = match alignment {
+                // Fills take up the rest of the line, so there's nothing left to align it with
+                _ if line.fills > 0 => 0,
+                Alignment::Left => 0,
+                alignment => alignment_offset(alignment, area.width, line.width),
+            };
 
-                // Our next item slice needs to be the item after the line fragment
-                item_offset += next_line_pos + 1;
+            let mut fills = Spread::new(area.width.saturating_sub(line.width), line.fills);
 
-                &slice[..next_line_pos]
+            let mut justify = if self.justify {
+                line.justify(alignment, area.width)
+            } else {
+                None
             };
 
-            let mut offset_x =
-                alignment_offset(self.alignment, area.width, Fragment::total_len(line_items));
+            let mut writer = LineWriter {
+                buf: buf.as_deref_mut(),
+                area,
+                y: area.y + offset_y,
+                scroll_x,
+            };
 
-            for item in line_items {
-                let start_x = area.x + offset_x;
-                let start_y = area.y + offset_y;
+            let mut offset_x = line_x;
+            let mut has_next_line = false;
+            let mut line_visible = true;
 
-                let len = match item {
-                    Fragment::Span(Span { content, style }, opts) => {
-                        let len = content.width() as u16;
+            for (index, item) in &mut items {
+                let len = match (item.borrow(), justify.as_mut()) {
+                    (Fragment::Line, _) => {
+                        has_next_line = true;
+                        break;
+                    }
+                    // An earlier item didn't fit, so the rest of the line is hidden
+                    _ if !line_visible => continue,
+                    (Fragment::Align(_), _) => 0,
+                    (Fragment::Fill(ch, style), _) => {
+                        let width = fills.next();
+                        writer.fill(offset_x, width, *ch, *style);
+                        width
+                    }
+                    // Justified lines always fit within the area
+                    (Fragment::Span(span, _), Some(justify)) => {
+                        draw_justified(&mut writer, offset_x, span, justify)
+                    }
+                    (Fragment::Char(ch, style), Some(justify)) => {
+                        let extra = justify.next(*ch);
+                        writer.fill_spaces(offset_x, extra, *style);
+                        extra + writer.put_char(offset_x + extra, *ch, *style)
+                    }
+                    (Fragment::Span(Span { content, style }, opts), None) => {
+                        let content = expand_tabs(content, offset_x - line_x, self.tab_width);
+                        let max_width = right.saturating_sub(offset_x);
 
-                        let max_width = match opts.overflow {
-                            OverflowMode::Hide => len,
-                            OverflowMode::Truncate => area.width.min(len),
+                        let Some(text) = opts.overflow.shorten(&content, max_width) else {
+                            line_visible = false;
+                            continue;
                         };
 
-                        if !Self::can_draw_at_x(area, offset_x + max_width) {
-                            break;
+                        let mut x = offset_x;
+
+                        for part in text.parts() {
+                            x += writer.put_str(x, part, *style);
+                        }
+
+                        // Nothing else can fit on the line after a span that had to be shortened
+                        if text.width() < content.width() as u16 {
+                            line_visible = false;
                         }
 
-                        buf.set_stringn(start_x, start_y, content, max_width as usize, *style);
-                        len
+                        text.width()
                     }
-                    Fragment::Char(ch, style) => {
-                        if !Self::can_draw_at_x(area, offset_x) {
-                            break;
+                    (Fragment::Char('\t', style), None) => {
+                        let width = tab_advance(offset_x - line_x, self.tab_width);
+                        let visible_width = width.min(right.saturating_sub(offset_x));
+
+                        writer.fill_spaces(offset_x, visible_width, *style);
+
+                        if visible_width < width {
+                            line_visible = false;
                         }
 
-                        buf.get_mut(start_x, start_y)
-                            .set_char(*ch)
-                            .set_style(*style);
+                        width
+                    }
+                    (Fragment::Char(ch, style), None) => {
+                        if offset_x >= right {
+                            line_visible = false;
+                            continue;
+                        }
 
-                        ch.width().unwrap_or(0) as u16
+                        writer.put_char(offset_x, *ch, *style)
                     }
-                    Fragment::Line => break,
                 };
 
-                offset_x += len;
+                if let Some(rect) = writer.visible_rect(offset_x, len) {
+                    visit(index, rect);
+                }
+
+                offset_x = offset_x.saturating_add(len);
             }
 
             offset_y += 1;
 
-            if !Self::can_draw_at_y(area, offset_y) {
+            if !has_next_line || !Self::can_draw_at_y(area, offset_y) {
                 break;
             }
         }
     }
+
+    /// Skip past the first line in the given `items`, while keeping track of the alignment it sets.
+    ///
+    /// Returns false if there was no line after it.
+    fn skip_line<L>(items: L, alignment: &mut Alignment) -> bool
+    where
+        L: Iterator,
+        L::Item: Borrow<Fragment<'a>>,
+    {
+        for item in items {
+            match item.borrow() {
+                Fragment::Line => return true,
+                Fragment::Align(line_alignment) => *alignment = *line_alignment,
+                _ => (),
+            }
+        }
+
+        false
+    }
+
+    fn can_draw_at_y(area: Rect, y: u16) -> bool {
+        y < area.height
+    }
+}
+
+impl<'a, I> Measure for TextFragments<'a, I>
+where
+    I: IntoIterator + Clone,
+    I::IntoIter: Clone,
+    I::Item: Borrow<Fragment<'a>>,
+{
+    fn measure(&self, max_width: u16) -> Measurement {
+        let mut width = 0;
+        let mut line_width: u16 = 0;
+        let mut height: u16 = 1;
+
+        for item in self.items.clone() {
+            match item.borrow() {
+                Fragment::Line => {
+                    width = width.max(line_width);
+                    line_width = 0;
+                    height = height.saturating_add(1);
+                }
+                item => {
+                    line_width =
+                        line_width.saturating_add(item.width_at(line_width, self.tab_width));
+                }
+            }
+        }
+
+        let width = width.max(line_width).min(max_width);
+
+        // Fragments that don't fit are hidden rather than shrunk, so the content can't get any smaller
+        Measurement {
+            width,
+            height,
+            min_width: width,
+            min_height: height,
+        }
+    }
+}
+
+impl<'a, I> HitTest for TextFragments<'a, I>
+where
+    I: IntoIterator + Clone,
+    I::IntoIter: Clone,
+    I::Item: Borrow<Fragment<'a>>,
+{
+    /// The index of a fragment. Fragments that take up no space, such as [`Fragment::Line`], are never included.
+    type Index = usize;
+
+    fn position_map(&self, area: Rect) -> PositionMap<usize> {
+        let mut positions = PositionMap::new();
+        self.draw(area, None, |index, rect| positions.push(index, rect));
+        positions
+    }
+}
+
+impl<'a, I> Widget for TextFragments<'a, I>
+where
+    I: IntoIterator + Clone,
+    I::IntoIter: Clone,
+    I::Item: Borrow<Fragment<'a>>,
+{
+    #[inline]
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.draw(area, Some(buf), |_, _| ());
+    }
+}
+
+/// A summary of a single line of fragments.
+#[derive(Default)]
+struct LineInfo {
+    width: u16,
+    /// The last alignment set by a `Fragment::Align` on the line.
+    alignment: Option<Alignment>,
+    /// The number of gaps between words on the line.
+    gaps: u16,
+    /// The number of `Fragment::Fill`'s on the line.
+    fills: u16,
+    has_tabs: bool,
+    /// Whether the line is the last one of its paragraph, which happens when it's followed by an empty line or no line at all.
+    paragraph_end: bool,
+}
+
+impl LineInfo {
+    /// Returns what's needed to justify the line, if it can be justified with the given `alignment`.
+    fn justify(&self, alignment: Alignment, area_width: u16) -> Option<Justify> {
+        let can_justify = self.fills == 0
+            && alignment == Alignment::Left
+            && !self.paragraph_end
+            && !self.has_tabs
+            && self.gaps > 0
+            && self.width < area_width;
+
+        can_justify.then(|| Justify {
+            spread: Spread::new(area_width - self.width, self.gaps),
+            ..Justify::default()
+        })
+    }
+}
+
+/// Shares a width evenly between a number of parts. Any width that can't be shared evenly goes to the first parts.
+#[derive(Default)]
+struct Spread {
+    width: u16,
+    parts: u16,
+    /// The number of parts that have been given their width so far.
+    index: u16,
+}
+
+impl Spread {
+    fn new(width: u16, parts: u16) -> Self {
+        Self {
+            width,
+            parts,
+            index: 0,
+        }
+    }
+
+    /// Returns the width of the next part.
+    fn next(&mut self) -> u16 {
+        self.index = self.index.saturating_add(1);
+
+        match self.parts {
+            0 => 0,
+            parts => self.width / parts + u16::from(self.index <= self.width % parts),
+        }
+    }
+}
+
+/// Spreads the space left over on a line between the gaps between its words.
+#[derive(Default)]
+struct Justify {
+    /// The extra width to place in each gap, which also counts the number of gaps passed so far.
+    spread: Spread,
+    seen_word: bool,
+    in_gap: bool,
+}
+
+impl Justify {
+    /// Returns the extra width to place before the given character.
+    fn next(&mut self, ch: char) -> u16 {
+        if ch.is_whitespace() {
+            self.in_gap |= self.seen_word;
+            return 0;
+        }
+
+        let extra = if self.in_gap { self.spread.next() } else { 0 };
+
+        self.in_gap = false;
+        self.seen_word = true;
+        extra
+    }
+}
+
+/// Draw the given `span` at the given `column` with the extra width of each gap it contains, and return the total width that was drawn.
+fn draw_justified(writer: &mut LineWriter, column: u16, span: &Span, justify: &mut Justify) -> u16 {
+    let content = &span.content;
+    let mut width = 0;
+    let mut run_start = 0;
+
+    for (pos, ch) in content.char_indices() {
+        let extra = justify.next(ch);
+
+        if extra == 0 {
+            continue;
+        }
+
+        width += writer.put_str(column + width, &content[run_start..pos], span.style);
+
+        writer.fill_spaces(column + width, extra, span.style);
+        width += extra;
+        run_start = pos;
+    }
+
+    width + writer.put_str(column + width, &content[run_start..], span.style)
+}
+
+/// Draws a single line into a buffer.
+///
+/// Columns are counted from the start of the line before it's scrolled, and anything outside of the visible columns is clipped.
+/// Wide characters that are only partly visible are replaced with spaces.
+struct LineWriter<'b> {
+    buf: Option<&'b mut Buffer>,
+    area: Rect,
+    y: u16,
+    scroll_x: u16,
+}
+
+impl LineWriter<'_> {
+    /// Returns the part of the screen that `width` cells starting at the given `column` are drawn to, if any of them are visible.
+    fn visible_rect(&self, column: u16, width: u16) -> Option<Rect> {
+        let start = column.max(self.scroll_x);
+        let end = column
+            .saturating_add(width)
+            .min(self.scroll_x.saturating_add(self.area.width));
+
+        if start >= end {
+            return None;
+        }
+
+        Some(Rect {
+            x: self.area.x + (start - self.scroll_x),
+            y: self.y,
+            width: end - start,
+            height: 1,
+        })
+    }
+
+    /// Draw a single `grapheme` that's `width` cells wide at the given `column`.
+    fn put(&mut self, column: u16, grapheme: &str, width: u16, style: Style) {
+        let Some(rect) = self.visible_rect(column, width) else {
+            return;
+        };
+
+        let Some(buf) = self.buf.as_deref_mut() else {
+            return;
+        };
+
+        if rect.width < width {
+            for x in rect.left()..rect.right() {
+                buf.get_mut(x, rect.y).set_char(' ').set_style(style);
+            }
+
+            return;
+        }
+
+        buf.get_mut(rect.x, rect.y)
+            .set_symbol(grapheme)
+            .set_style(style);
+
+        // The cells a wide character covers have to be cleared, just like `Buffer::set_string` does
+        for x in rect.left() + 1..rect.right() {
+            buf.get_mut(x, rect.y).reset();
+        }
+    }
+
+    /// Draw the given `text` at the given `column`, and return its total width.
+    fn put_str(&mut self, column: u16, text: &str, style: Style) -> u16 {
+        let mut width: u16 = 0;
+
+        for grapheme in text.graphemes(true) {
+            let grapheme_width = grapheme.width() as u16;
+
+            if grapheme_width == 0 {
+                continue;
+            }
+
+            self.put(
+                column.saturating_add(width),
+                grapheme,
+                grapheme_width,
+                style,
+            );
+            width = width.saturating_add(grapheme_width);
+        }
+
+        width
+    }
+
+    /// Draw the given character at the given `column`, and return its width.
+    fn put_char(&mut self, column: u16, ch: char, style: Style) -> u16 {
+        let width = ch.width().unwrap_or(0) as u16;
+
+        if width > 0 {
+            self.put(column, ch.encode_utf8(&mut [0; 4]), width, style);
+        }
+
+        width
+    }
+
+    /// Fill `width` cells starting at the given `column` with spaces.
+    fn fill_spaces(&mut self, column: u16, width: u16, style: Style) {
+        self.fill(column, width, ' ', style);
+    }
+
+    /// Fill `width` cells starting at the given `column` with the given character.
+    /// Any cells that are too narrow to fit the character are filled with spaces instead.
+    fn fill(&mut self, column: u16, width: u16, ch: char, style: Style) {
+        let ch_width = ch.width().unwrap_or(0).max(1) as u16;
+        let mut symbol = [0; 4];
+        let symbol = ch.encode_utf8(&mut symbol);
+        let mut offset = 0;
+
+        while offset < width {
+            if offset + ch_width > width {
+                self.put(column + offset, " ", 1, style);
+                offset += 1;
+                continue;
+            }
+
+            self.put(column + offset, symbol, ch_width, style);
+            offset += ch_width;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextFragments;
+    use crate::widgets::{Fragment, OverflowMode, SpanOptions};
+    use crate::wrap::{WrapMode, WrapOptions, Wrapped};
+    use std::borrow::Borrow;
+    use tui::{
+        backend::TestBackend, buffer::Buffer, layout::Alignment, style::Style, text::Span,
+        widgets::Widget, Terminal,
+    };
+
+    fn assert_rendered<'a, I>(fragments: TextFragments<'a, I>, expected: Vec<&str>)
+    where
+        I: IntoIterator + Clone,
+        I::IntoIter: Clone,
+        I::Item: Borrow<Fragment<'a>>,
+        TextFragments<'a, I>: Widget,
+    {
+        let expected = Buffer::with_lines(expected);
+        let backend = TestBackend::new(expected.area.width, expected.area.height);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| f.render_widget(fragments, f.size()))
+            .unwrap();
+
+        terminal.backend().assert_buffer(&expected);
+    }
+
+    #[test]
+    fn tabs_across_fragments() {
+        let fragments = [
+            Fragment::span("ab"),
+            Fragment::span("\tc"),
+            Fragment::Char('\t', Style::default()),
+            Fragment::span("d"),
+        ];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]).tab_width(4),
+            vec!["ab  c   d "],
+        );
+    }
+
+    #[test]
+    fn tabs_aligned() {
+        let fragments = [Fragment::span("\tx")];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..])
+                .tab_width(4)
+                .alignment(Alignment::Center),
+            vec!["       x  "],
+        );
+    }
+
+    #[test]
+    fn justify_lines() {
+        let fragments = [
+            Fragment::span("aa bb cc"),
+            Fragment::Line,
+            Fragment::span("a "),
+            Fragment::span("b c"),
+            Fragment::Line,
+            Fragment::span("dd ee"),
+        ];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]).justify(true),
+            vec!["aa  bb  cc", "a    b   c", "dd ee     "],
+        );
+    }
+
+    #[test]
+    fn justify_paragraphs() {
+        let fragments = [
+            Fragment::span("aa bb"),
+            Fragment::Line,
+            Fragment::Line,
+            Fragment::span("  cc dd"),
+            Fragment::Line,
+            Fragment::span("ee"),
+        ];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]).justify(true),
+            vec!["aa bb    ", "         ", "  cc   dd", "ee       "],
+        );
+    }
+
+    #[test]
+    fn align_lines() {
+        let fragments = [
+            Fragment::Align(Alignment::Center),
+            Fragment::span("Title"),
+            Fragment::Line,
+            Fragment::Align(Alignment::Left),
+            Fragment::span("body"),
+            Fragment::Line,
+            Fragment::span("ab"),
+            Fragment::Align(Alignment::Right),
+            Fragment::Line,
+            Fragment::span("c"),
+        ];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]),
+            vec!["  Title  ", "body     ", "       ab", "        c"],
+        );
+    }
+
+    #[test]
+    fn fill_remaining_width() {
+        let fragments = [
+            Fragment::span("left"),
+            Fragment::Fill('.', Style::default()),
+            Fragment::span("right"),
+            Fragment::Line,
+            Fragment::Fill('-', Style::default()),
+            Fragment::span("mid"),
+            Fragment::Fill('=', Style::default()),
+            Fragment::Line,
+            Fragment::Fill('你', Style::default()),
+            Fragment::span("ab"),
+        ];
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]).alignment(Alignment::Right),
+            vec!["left..right", "----mid====", "你你你你 ab"],
+        );
+    }
+
+    #[test]
+    fn tabs_wrapped() {
+        let fragments = [Fragment::span("a\tb\tc")];
+        let options = WrapOptions::new(8).tab_width(4);
+        let wrapped = Wrapped::new(&fragments, WrapMode::Words, options);
+
+        assert_rendered(
+            TextFragments::new(wrapped).tab_width(4),
+            vec!["a   b   ", "c       "],
+        );
+    }
+
+    #[test]
+    fn scroll_lines() {
+        let fragments = [
+            Fragment::span("one"),
+            Fragment::Line,
+            Fragment::Align(Alignment::Right),
+            Fragment::span("two"),
+            Fragment::Line,
+            Fragment::span("three"),
+            Fragment::Line,
+            Fragment::span("four"),
+        ];
+
+        let text = TextFragments::new(&fragments[..]);
+        assert_eq!(text.content_height(), 4);
+
+        // The alignment set on a skipped line still applies to the lines after it
+        assert_rendered(text.scroll(2, 0), vec![" three", "  four", "      "]);
+
+        assert_rendered(
+            TextFragments::new(&fragments[..]).scroll(4, 0),
+            vec!["      "],
+        );
+    }
+
+    #[test]
+    fn scroll_columns() {
+        let truncate = SpanOptions::new().overflow(OverflowMode::Truncate);
+
+        let fragments = [
+            Fragment::Span(Span::raw("a你好b"), truncate),
+            Fragment::Line,
+            Fragment::span("a\tb"),
+            Fragment::Line,
+            Fragment::span("wxyz"),
+            Fragment::Char('你', Style::default()),
+            Fragment::Line,
+            Fragment::Span(Span::raw("abcdefg"), truncate),
+        ];
+
+        // Wide characters cut off by either edge are drawn as spaces
+        assert_rendered(
+            TextFragments::new(&fragments[..]).tab_width(4).scroll(0, 2),
+            vec![" 好", "  b", "yz ", "cde"],
+        );
+    }
 }
@@ -0,0 +1,382 @@
+use super::line::{self, fragment_len, Cut, Position};
+use super::{next_line, truncate_line, LineStart, WrapMode, WrapOptions};
+use crate::widgets::Fragment;
+use std::borrow::Cow;
+use tui::text::Span;
+
+/// An iterator that lazily wraps a list of fragments.
+///
+/// Unlike [`by_letters`](super::by_letters), [`by_words`](super::by_words), and [`by_newlines`](super::by_newlines),
+/// this doesn't copy the content of the given fragments. Each `Fragment::Span` that is yielded borrows its content from them.
+///
+/// The prefix and ellipsis from [`WrapOptions`] are cloned every time they're yielded, and whenever the iterator itself is cloned,
+/// which [`TextFragments`](crate::widgets::TextFragments) does for each line it draws. Give them spans that borrow their content,
+/// such as `Fragment::span("│ ")`, rather than an owned `String` to keep wrapping free of allocations.
+///
+/// This can be given directly to the [`TextFragments`](crate::widgets::TextFragments) widget.
+#[derive(Clone)]
+pub struct Wrapped<'a, 'b> {
+    fragments: &'b [Fragment<'a>],
+    mode: WrapMode,
+    options: WrapOptions<'b>,
+    /// How the current line starts, and the width left for its content.
+    line: LineStart,
+    /// The width of the indent that has yet to be yielded for the current line.
+    indent: u16,
+    /// Whether the prefix has yet to be yielded for the current line.
+    prefix: bool,
+    /// The number of the current line, starting from 1.
+    lines: u16,
+    /// Whether the ellipsis should be yielded after the last line.
+    ellipsis: bool,
+    pos: Position,
+    cut: Option<Cut>,
+}
+
+impl<'a, 'b> Wrapped<'a, 'b> {
+    /// Wrap the given `fragments` with the given `mode` to fit within the width given by `options`, which can also be a `u16`.
+    ///
+    /// The width is ignored when wrapping by [`WrapMode::Newlines`].
+    #[inline]
+    #[must_use]
+    pub fn new<O>(fragments: &'b [Fragment<'a>], mode: WrapMode, options: O) -> Self
+    where
+        O: Into<WrapOptions<'b>>,
+    {
+        let mut options = options.into();
+
+        if mode == WrapMode::Newlines {
+            options.area_width = u16::MAX;
+        }
+
+        let mut wrapped = Self {
+            fragments,
+            mode,
+            options,
+            line: LineStart {
+                indent: 0,
+                prefix: false,
+                width: 0,
+                column: 0,
+                tab_width: 0,
+            },
+            indent: 0,
+            prefix: false,
+            lines: 0,
+            ellipsis: false,
+            pos: (fragments.len(), 0),
+            cut: None,
+        };
+
+        let has_space = wrapped.options.area_width > 0 && wrapped.options.max_lines != Some(0);
+
+        if has_space && !fragments.is_empty() {
+            wrapped.pos = (0, 0);
+            wrapped.start_line(true);
+        }
+
+        wrapped
+    }
+
+    /// Returns whether any lines are cut off by [`WrapOptions::max_lines`].
+    ///
+    /// This always looks at the fragments from the beginning, so it can be called at any point during iteration.
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        let Some(max_lines) = self.options.max_lines else {
+            return false;
+        };
+
+        if max_lines == 0 {
+            return !self.fragments.is_empty();
+        }
+
+        let mut pos = (0, 0);
+        let mut line = self.options.line_start(true);
+
+        for _ in 1..max_lines {
+            pos = match next_line(self.fragments, pos, self.mode, line) {
+                Some(pos) => pos,
+                None => return false,
+            };
+
+            line = self.options.line_start(false);
+        }
+
+        next_line(self.fragments, pos, self.mode, line).is_some()
+    }
+
+    /// Prepare to yield the line beginning at the current position.
+    fn start_line(&mut self, first: bool) {
+        let line = self.options.line_start(first);
+
+        self.line = line;
+        self.indent = line.indent;
+        self.prefix = line.prefix;
+        self.lines = self.lines.saturating_add(1);
+
+        if self.options.max_lines == Some(self.lines) {
+            self.cut = truncate_line(self.fragments, self.pos, self.mode, line, &self.options);
+            self.ellipsis = self.cut.is_some() && self.options.ellipsis.len() <= line.width;
+        }
+    }
+}
+
+impl<'b> Iterator for Wrapped<'_, 'b> {
+    type Item = Fragment<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(indent) = super::indent(self.indent).next() {
+            self.indent -= indent.len();
+            return Some(indent);
+        }
+
+        if self.prefix {
+            self.prefix = false;
+            return self.options.prefix.clone();
+        }
+
+        loop {
+            let cut = match self.cut {
+                Some(cut) => cut,
+                None if self.pos.0 >= self.fragments.len() => {
+                    return std::mem::take(&mut self.ellipsis)
+                        .then(|| self.options.ellipsis.clone());
+                }
+                None => {
+                    let cut = line::next_cut(self.fragments, self.pos, self.mode, self.line);
+                    self.cut = Some(cut);
+                    cut
+                }
+            };
+
+            if self.pos < cut.start {
+                let (index, from) = self.pos;
+                let fragment = &self.fragments[index];
+
+                self.pos = if cut.start.0 == index {
+                    cut.start
+                } else {
+                    (index + 1, 0)
+                };
+
+                let to = if cut.start.0 == index {
+                    cut.start.1
+                } else {
+                    fragment_len(fragment)
+                };
+
+                if from < to {
+                    return Some(borrow_fragment(fragment, from, to));
+                }
+
+                continue;
+            }
+
+            self.pos = cut.end;
+            self.cut = None;
+
+            if cut.line {
+                self.start_line(false);
+                return Some(Fragment::Line);
+            }
+        }
+    }
+}
+
+/// Returns a fragment that borrows the content between `from` and `to` of the given `fragment`.
+fn borrow_fragment<'b>(fragment: &'b Fragment, from: usize, to: usize) -> Fragment<'b> {
+    match fragment {
+        Fragment::Span(span, opts) => {
+            let span = Span {
+                content: Cow::Borrowed(&span.content[from..to]),
+                style: span.style,
+            };
+
+            Fragment::Span(span, *opts)
+        }
+        Fragment::Char(ch, style) => Fragment::Char(*ch, *style),
+        Fragment::Line => Fragment::Line,
+        Fragment::Align(alignment) => Fragment::Align(*alignment),
+        Fragment::Fill(ch, style) => Fragment::Fill(*ch, *style),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wrapped;
+    use crate::widgets::{Fragment, SpanOptions};
+    use crate::wrap::{self, WrapMode, WrapOptions};
+    use std::borrow::Cow;
+    use tui::{
+        style::{Color, Style},
+        text::Span,
+    };
+
+    fn test_fragments() -> [Fragment<'static>; 6] {
+        [
+            Fragment::span("this is a test of "),
+            Fragment::span(String::from("wrapping long")),
+            Fragment::Char(' ', Style::default()),
+            Fragment::span("sentences\nwith a newline"),
+            Fragment::Line,
+            Fragment::span("  and averyveryverylongword"),
+        ]
+    }
+
+    #[test]
+    fn wrapped_matches_eager() {
+        let fragments = test_fragments();
+
+        for &width in &[1, 5, 10, 16, 80] {
+            let eager = [
+                (
+                    WrapMode::Letters,
+                    wrap::by_letters(fragments.clone(), width).0,
+                ),
+                (WrapMode::Words, wrap::by_words(fragments.clone(), width).0),
+                (WrapMode::Newlines, wrap::by_newlines(fragments.clone())),
+            ];
+
+            for (mode, expected) in &eager {
+                let lazy = Wrapped::new(&fragments, *mode, width).collect::<Vec<_>>();
+                assert_eq!(lazy, expected.as_slice(), "{mode:?} at width {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_options_match_eager() {
+        let fragments = test_fragments();
+
+        for &width in &[1, 5, 10, 16, 50] {
+            let options = WrapOptions::new(width)
+                .initial_indent(2)
+                .subsequent_indent(40)
+                .prefix(Fragment::span("│ "));
+
+            let eager = [
+                (
+                    WrapMode::Letters,
+                    wrap::by_letters(fragments.clone(), options.clone()).0,
+                ),
+                (
+                    WrapMode::Words,
+                    wrap::by_words(fragments.clone(), options.clone()).0,
+                ),
+            ];
+
+            for (mode, expected) in &eager {
+                let lazy = Wrapped::new(&fragments, *mode, options.clone()).collect::<Vec<_>>();
+                assert_eq!(lazy, expected.as_slice(), "{mode:?} at width {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_max_lines_match_eager() {
+        let fragments = test_fragments();
+
+        for &width in &[1, 5, 10, 16, 80] {
+            for max_lines in 0..6 {
+                let options = WrapOptions::new(width)
+                    .subsequent_indent(1)
+                    .max_lines(max_lines);
+
+                let eager = [
+                    (
+                        WrapMode::Letters,
+                        wrap::by_letters(fragments.clone(), options.clone()),
+                    ),
+                    (
+                        WrapMode::Words,
+                        wrap::by_words(fragments.clone(), options.clone()),
+                    ),
+                ];
+
+                for (mode, (expected, truncated)) in &eager {
+                    let wrapped = Wrapped::new(&fragments, *mode, options.clone());
+                    let lines = Wrapped::new(&fragments, *mode, width)
+                        .filter(|fragment| *fragment == Fragment::Line)
+                        .count();
+
+                    assert_eq!(wrapped.is_truncated(), lines >= usize::from(max_lines));
+                    assert_eq!(wrapped.is_truncated(), *truncated);
+                    assert_eq!(
+                        wrapped.collect::<Vec<_>>(),
+                        expected.as_slice(),
+                        "{mode:?} at width {width} with {max_lines} lines"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_max_lines() {
+        let fragments = [Fragment::span("this is a long description")];
+        let style = Style::default().fg(Color::DarkGray);
+        let ellipsis = Fragment::Span(Span::styled("...", style), SpanOptions::default());
+        let options = WrapOptions::new(10).max_lines(2).ellipsis(ellipsis);
+
+        let wrapped = Wrapped::new(&fragments, WrapMode::Words, options);
+        assert!(wrapped.is_truncated());
+
+        assert_eq!(
+            wrapped.collect::<Vec<_>>(),
+            [
+                Fragment::span("this is a"),
+                Fragment::Line,
+                Fragment::span("long"),
+                Fragment::span("...")
+            ]
+        );
+
+        let wrapped = Wrapped::new(
+            &fragments,
+            WrapMode::Words,
+            WrapOptions::new(10).max_lines(4),
+        );
+        assert!(!wrapped.is_truncated());
+        assert_eq!(
+            wrapped
+                .filter(|fragment| *fragment == Fragment::Line)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn wrapped_borrows_content() {
+        let fragments = test_fragments();
+
+        for fragment in Wrapped::new(&fragments, WrapMode::Words, 10) {
+            if let Fragment::Span(Span { content, .. }, _) = fragment {
+                assert!(matches!(content, Cow::Borrowed(_)));
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_no_space() {
+        let fragments = test_fragments();
+
+        assert_eq!(Wrapped::new(&fragments, WrapMode::Letters, 0).count(), 0);
+        assert_eq!(Wrapped::new(&fragments, WrapMode::Words, 0).count(), 0);
+    }
+
+    #[test]
+    fn wrapped_newlines() {
+        let fragments = [Fragment::span("first\nsecond line")];
+        let result = Wrapped::new(&fragments, WrapMode::Words, 20).collect::<Vec<_>>();
+
+        assert_eq!(
+            result,
+            [
+                Fragment::span("first"),
+                Fragment::Line,
+                Fragment::span("second line")
+            ]
+        );
+    }
+}
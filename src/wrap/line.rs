@@ -0,0 +1,355 @@
+use super::{LineStart, WrapMode};
+use crate::widgets::{str_width_at, Fragment};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+
+/// A position within a list of fragments, made up of the index of a fragment and a byte offset into its content.
+///
+/// Fragments other than `Fragment::Span` have a length of 1, so an offset of 1 is the position right after them.
+pub(super) type Position = (usize, usize);
+
+/// A place where a line of fragments has to be broken.
+#[derive(Clone, Copy)]
+pub(super) struct Cut {
+    /// The position where the current line ends.
+    pub start: Position,
+    /// The position where the next line starts. Anything between `start` and `end` is removed.
+    pub end: Position,
+    /// Whether a `Fragment::Line` should be inserted between both lines.
+    pub line: bool,
+}
+
+/// Returns the length of the given `fragment` in the units used by [`Position`].
+pub(super) fn fragment_len(fragment: &Fragment) -> usize {
+    match fragment {
+        Fragment::Span(span, _) => span.content.len(),
+        Fragment::Char(..) | Fragment::Line | Fragment::Align(_) | Fragment::Fill(..) => 1,
+    }
+}
+
+/// Find where the line beginning at the given `start` position has to be broken.
+///
+/// When there is nothing left to break, the returned cut starts and ends after the last fragment.
+pub(super) fn next_cut(
+    fragments: &[Fragment],
+    start: Position,
+    mode: WrapMode,
+    line: LineStart,
+) -> Cut {
+    match mode {
+        WrapMode::Newlines => next_newline(fragments, start),
+        WrapMode::Letters | WrapMode::Words => {
+            LineBreaker::new(fragments, mode, line).next_cut(start)
+        }
+    }
+}
+
+fn next_newline(fragments: &[Fragment], start: Position) -> Cut {
+    for (index, fragment) in fragments.iter().enumerate().skip(start.0) {
+        let from = if index == start.0 { start.1 } else { 0 };
+
+        let pos = match fragment {
+            Fragment::Span(span, _) => match span.content[from..].find('\n') {
+                Some(pos) => from + pos,
+                None => continue,
+            },
+            Fragment::Char('\n', _) | Fragment::Line if from == 0 => 0,
+            Fragment::Char(..) | Fragment::Line | Fragment::Align(_) | Fragment::Fill(..) => {
+                continue
+            }
+        };
+
+        return Cut {
+            start: (index, pos),
+            end: (index, pos + 1),
+            line: true,
+        };
+    }
+
+    let end = (fragments.len(), 0);
+
+    Cut {
+        start: end,
+        end,
+        line: false,
+    }
+}
+
+/// Finds where a line has to be broken when wrapping by letters or words.
+struct LineBreaker<'a, 'b> {
+    fragments: &'b [Fragment<'a>],
+    mode: WrapMode,
+    area_width: u16,
+    /// The column the line starts at, which tab stops are found from.
+    column: u16,
+    tab_width: u16,
+    line_length: u16,
+    /// The whitespace following the last word on the current line, which is removed if the line is broken after it.
+    gap: Option<(Position, u16)>,
+    /// The word currently being measured, which may continue into the next fragment.
+    word: Option<(Position, u16)>,
+    /// Whether the last letter ended with a zero-width joiner, which means the line can't be broken before the next one.
+    joined: bool,
+}
+
+impl<'a, 'b> LineBreaker<'a, 'b> {
+    fn new(fragments: &'b [Fragment<'a>], mode: WrapMode, line: LineStart) -> Self {
+        Self {
+            fragments,
+            mode,
+            area_width: line.width,
+            column: line.column,
+            tab_width: line.tab_width,
+            line_length: 0,
+            gap: None,
+            word: None,
+            joined: false,
+        }
+    }
+
+    fn next_cut(mut self, start: Position) -> Cut {
+        let fragments = self.fragments;
+
+        for (index, fragment) in fragments.iter().enumerate().skip(start.0) {
+            let from = if index == start.0 { start.1 } else { 0 };
+            let words = self.mode == WrapMode::Words;
+
+            let cut = match fragment {
+                Fragment::Span(span, _) if words => self.push_words(index, from, &span.content),
+                Fragment::Span(span, _) => self.push_letters(index, from, &span.content),
+                // This fragment has already been placed on the previous line
+                Fragment::Char(..) | Fragment::Line | Fragment::Align(_) | Fragment::Fill(..)
+                    if from > 0 =>
+                {
+                    None
+                }
+                // Fills can be removed when a line is broken at them, just like whitespace
+                Fragment::Fill(..) if words => self.push_whitespace((index, 0), ""),
+                Fragment::Align(_) | Fragment::Fill(..) => None,
+                Fragment::Char('\n', _) | Fragment::Line => {
+                    Some(self.end_line((index, 0), (index, 1)))
+                }
+                Fragment::Char(ch, _) if words && ch.is_whitespace() => {
+                    self.push_whitespace((index, 0), ch.encode_utf8(&mut [0; 4]))
+                }
+                Fragment::Char(..) if words => {
+                    self.push_word((index, 0), fragment.len());
+                    None
+                }
+                Fragment::Char(ch, _) => {
+                    self.push_grapheme((index, 0), ch.encode_utf8(&mut [0; 4]))
+                }
+            };
+
+            if let Some(cut) = cut {
+                return cut;
+            }
+        }
+
+        let end = (fragments.len(), 0);
+        self.end_line(end, end)
+    }
+
+    fn push_letters(&mut self, index: usize, from: usize, content: &str) -> Option<Cut> {
+        for (pos, grapheme) in content[from..].grapheme_indices(true) {
+            let pos = from + pos;
+
+            if grapheme == "\n" || grapheme == "\r\n" {
+                return Some(self.end_line((index, pos), (index, pos + grapheme.len())));
+            }
+
+            if let Some(cut) = self.push_grapheme((index, pos), grapheme) {
+                return Some(cut);
+            }
+        }
+
+        None
+    }
+
+    fn push_words(&mut self, index: usize, from: usize, content: &str) -> Option<Cut> {
+        let content = &content[from..];
+        let mut start = 0;
+
+        for (end, _) in unicode_linebreak::linebreaks(content) {
+            let segment = &content[start..end];
+
+            let line = segment
+                .strip_suffix('\n')
+                .map(|line| line.strip_suffix('\r').unwrap_or(line));
+
+            let text = line.unwrap_or(segment);
+            let word_len = text.trim_end().len();
+
+            if word_len > 0 {
+                self.push_word((index, from + start), text[..word_len].width() as u16);
+            }
+
+            let cut = if word_len < text.len() {
+                self.push_whitespace((index, from + start + word_len), &text[word_len..])
+            } else if line.is_none() && end < content.len() {
+                // A break is allowed here without any whitespace, such as after a hyphen
+                self.end_word((index, from + end))
+            } else {
+                None
+            };
+
+            if cut.is_some() {
+                return cut;
+            }
+
+            if let Some(text) = line {
+                let line_end = (index, from + start + text.len());
+                return Some(self.end_line(line_end, (index, from + end)));
+            }
+
+            start = end;
+        }
+
+        None
+    }
+
+    fn push_word(&mut self, pos: Position, width: u16) {
+        self.word = match self.word {
+            Some((start, word_width)) => Some((start, word_width.saturating_add(width))),
+            None => Some((pos, width)),
+        };
+    }
+
+    fn push_whitespace(&mut self, pos: Position, whitespace: &str) -> Option<Cut> {
+        let cut = self.end_word(pos);
+
+        if cut.is_none() {
+            let gap_width = self.gap.map_or(0, |(_, width)| width);
+            let width = self.width_at(whitespace, self.line_length.saturating_add(gap_width));
+
+            self.gap = match self.gap {
+                Some((start, gap_width)) => Some((start, gap_width.saturating_add(width))),
+                None => Some((pos, width)),
+            };
+        }
+
+        cut
+    }
+
+    /// Place the word currently being measured, which ends at the given `end` position.
+    fn end_word(&mut self, end: Position) -> Option<Cut> {
+        let (start, width) = self.word.take()?;
+        let gap_width = self.gap.map_or(0, |(_, width)| width);
+
+        if self.fits(gap_width.saturating_add(width)) {
+            self.line_length += gap_width + width;
+            self.gap = None;
+            return None;
+        }
+
+        if self.line_length > 0 {
+            return Some(Cut {
+                start: self.gap.map_or(start, |(pos, _)| pos),
+                end: start,
+                line: true,
+            });
+        }
+
+        // Whitespace at the start of a line is kept as indentation
+        self.line_length = gap_width;
+        self.gap = None;
+
+        self.break_letters(start, end)
+    }
+
+    /// Wrap the word between `start` and `end` by its letters, as it can't fit on a line by itself.
+    fn break_letters(&mut self, start: Position, end: Position) -> Option<Cut> {
+        let fragments = self.fragments;
+
+        for (index, fragment) in fragments.iter().enumerate().take(end.0 + 1).skip(start.0) {
+            let from = if index == start.0 { start.1 } else { 0 };
+
+            let cut = match fragment {
+                Fragment::Span(span, _) => {
+                    let to = if index == end.0 {
+                        end.1
+                    } else {
+                        span.content.len()
+                    };
+
+                    span.content[from..to]
+                        .grapheme_indices(true)
+                        .find_map(|(pos, grapheme)| {
+                            self.push_grapheme((index, from + pos), grapheme)
+                        })
+                }
+                Fragment::Char(ch, _) if index < end.0 => {
+                    self.push_letter((index, 0), fragment.len(), *ch == ZERO_WIDTH_JOINER)
+                }
+                Fragment::Char(..) | Fragment::Line | Fragment::Align(_) | Fragment::Fill(..) => {
+                    None
+                }
+            };
+
+            if cut.is_some() {
+                return cut;
+            }
+        }
+
+        None
+    }
+
+    fn push_grapheme(&mut self, pos: Position, grapheme: &str) -> Option<Cut> {
+        let joiner = grapheme.ends_with(ZERO_WIDTH_JOINER);
+        self.push_letter(pos, self.width_at(grapheme, self.line_length), joiner)
+    }
+
+    /// Place a single letter with the given `width` on the current line.
+    ///
+    /// Letters that are too wide to fit are moved to the next line as a whole, and letters that don't fit on a line by themselves are placed on their own.
+    fn push_letter(&mut self, pos: Position, width: u16, ends_joined: bool) -> Option<Cut> {
+        let joined = std::mem::replace(&mut self.joined, ends_joined);
+
+        if !self.fits(width) && self.line_length > 0 && !joined {
+            self.joined = false;
+
+            return Some(Cut {
+                start: pos,
+                end: pos,
+                line: true,
+            });
+        }
+
+        self.line_length = self.line_length.saturating_add(width);
+        None
+    }
+
+    /// Finish the current line, which ends at `end`. The next line starts at `resume`.
+    fn end_line(&mut self, end: Position, resume: Position) -> Cut {
+        if let Some(cut) = self.end_word(end) {
+            return cut;
+        }
+
+        if let Some((start, gap_width)) = self.gap {
+            if !self.fits(gap_width) {
+                return Cut {
+                    start,
+                    end,
+                    line: false,
+                };
+            }
+        }
+
+        Cut {
+            start: end,
+            end: resume,
+            line: end != resume,
+        }
+    }
+
+    /// Returns the width of the given `text` when it starts at the given `offset` into the line.
+    fn width_at(&self, text: &str, offset: u16) -> u16 {
+        str_width_at(text, self.column.saturating_add(offset), self.tab_width)
+    }
+
+    fn fits(&self, width: u16) -> bool {
+        self.line_length.saturating_add(width) <= self.area_width
+    }
+}
@@ -0,0 +1,1221 @@
+pub mod iter;
+mod line;
+
+pub use iter::Wrapped;
+
+use crate::widgets::{Fragment, OverflowMode, TAB_WIDTH};
+use line::{fragment_len, Cut, Position};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use tui::text::Span;
+
+/// The method used to wrap fragments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Only wrap at newline (`\n`) characters.
+    Newlines,
+    /// Wrap at newline characters and any letter that would overflow the line.
+    ///
+    /// Letters are grapheme clusters, so wide characters, emoji sequences, and combining marks are never split across lines.
+    /// The only letters that may overflow the line are the ones that are wider than the entire line by themselves.
+    Letters,
+    /// Wrap at newline characters, whitespace, and the other line break opportunities defined by the Unicode line breaking algorithm.
+    ///
+    /// Whitespace at the end of a wrapped line is removed, and words that are too long to fit on a line are wrapped by their letters instead.
+    /// Breaks between two fragments are only made when there is whitespace between them, so a word can be split across several differently styled spans.
+    Words,
+}
+
+/// Spaces that line indents borrow their content from, so they don't have to be allocated.
+const SPACES: &str = "                                ";
+
+/// Options to control the width and indentation of wrapped lines.
+///
+/// The indent and prefix of a line count towards the `area_width`, so they never cause a line to overflow it.
+/// When there isn't enough space left for at least one letter after them, they are left out of that line.
+#[derive(Debug, Clone)]
+pub struct WrapOptions<'a> {
+    /// The width of the area to wrap fragments within.
+    pub area_width: u16,
+    /// The number of spaces to place at the start of the first line.
+    pub initial_indent: u16,
+    /// The number of spaces to place at the start of every line after the first.
+    pub subsequent_indent: u16,
+    /// A fragment to place at the start of every line after the first, following its indent.
+    pub prefix: Option<Fragment<'a>>,
+    /// The maximum number of lines to wrap the fragments into. Any lines past it are cut off, and the last line ends with the `ellipsis`.
+    pub max_lines: Option<u16>,
+    /// The fragment placed at the end of the last line when lines are cut off by `max_lines`.
+    ///
+    /// The content of the last line is shortened to make room for it, and it's left out when the line is too narrow to fit it by itself.
+    pub ellipsis: Fragment<'a>,
+    /// The number of columns between each tab stop. Tabs advance to the next tab stop, which is found from the column they start at, including the indent and prefix.
+    ///
+    /// This defaults to 8, and should match the tab width of the widget the fragments are drawn with. Tabs take up no space when set to 0.
+    pub tab_width: u16,
+}
+
+impl<'a> WrapOptions<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(area_width: u16) -> Self {
+        Self {
+            area_width,
+            initial_indent: 0,
+            subsequent_indent: 0,
+            prefix: None,
+            max_lines: None,
+            ellipsis: Fragment::span(OverflowMode::ELLIPSIS),
+            tab_width: TAB_WIDTH,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn initial_indent(mut self, indent: u16) -> Self {
+        self.initial_indent = indent;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn subsequent_indent(mut self, indent: u16) -> Self {
+        self.subsequent_indent = indent;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn prefix(mut self, prefix: Fragment<'a>) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn max_lines(mut self, max_lines: u16) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ellipsis(mut self, ellipsis: Fragment<'a>) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Returns how the first line, or any line after it, should start.
+    fn line_start(&self, first: bool) -> LineStart {
+        let (indent, prefix) = if first {
+            (self.initial_indent, None)
+        } else {
+            (self.subsequent_indent, self.prefix.as_ref())
+        };
+
+        let used = indent.saturating_add(prefix.map_or(0, Fragment::len));
+
+        if used < self.area_width {
+            LineStart {
+                indent,
+                prefix: prefix.is_some(),
+                width: self.area_width - used,
+                column: used,
+                tab_width: self.tab_width,
+            }
+        } else {
+            LineStart {
+                indent: 0,
+                prefix: false,
+                width: self.area_width,
+                column: 0,
+                tab_width: self.tab_width,
+            }
+        }
+    }
+
+    /// Returns the fragments to place at the start of a line.
+    fn decoration(&self, start: LineStart) -> impl Iterator<Item = Fragment<'a>> + '_ {
+        let prefix = self.prefix.iter().filter(move |_| start.prefix).cloned();
+        indent(start.indent).chain(prefix)
+    }
+}
+
+impl From<u16> for WrapOptions<'_> {
+    #[inline]
+    fn from(area_width: u16) -> Self {
+        Self::new(area_width)
+    }
+}
+
+/// How a line starts, and the width left for its content.
+#[derive(Clone, Copy)]
+struct LineStart {
+    indent: u16,
+    prefix: bool,
+    width: u16,
+    /// The column the content of the line starts at.
+    column: u16,
+    tab_width: u16,
+}
+
+/// Returns spans made up of `width` spaces in total.
+fn indent<'a>(width: u16) -> impl Iterator<Item = Fragment<'a>> {
+    let width = usize::from(width);
+
+    (0..width)
+        .step_by(SPACES.len())
+        .map(move |start| Fragment::span(&SPACES[..(width - start).min(SPACES.len())]))
+}
+
+/// Wrap the given `fragments` iterator by its letters to fit within the width given by `options`, which can also be a `u16`.
+///
+/// Returns a new `SmallVec` containing the given `fragments` with `Fragment::Line`'s inserted at appropriate places,
+/// along with whether any lines were cut off by [`WrapOptions::max_lines`].
+/// See [`WrapMode::Letters`] for more details, and [`Wrapped`] for a version that doesn't allocate.
+#[inline]
+pub fn by_letters<'a, I, O>(fragments: I, options: O) -> (SmallVec<[Fragment<'a>; 4]>, bool)
+where
+    I: IntoIterator<Item = Fragment<'a>>,
+    O: Into<WrapOptions<'a>>,
+{
+    wrap(fragments, WrapMode::Letters, &options.into())
+}
+
+/// Wrap the given `fragments` iterator by its words to fit within the width given by `options`, which can also be a `u16`.
+///
+/// Returns a new `SmallVec` containing the given `fragments` with `Fragment::Line`'s inserted at appropriate places,
+/// along with whether any lines were cut off by [`WrapOptions::max_lines`].
+/// See [`WrapMode::Words`] for more details, and [`Wrapped`] for a version that doesn't allocate.
+#[inline]
+pub fn by_words<'a, I, O>(fragments: I, options: O) -> (SmallVec<[Fragment<'a>; 4]>, bool)
+where
+    I: IntoIterator<Item = Fragment<'a>>,
+    O: Into<WrapOptions<'a>>,
+{
+    wrap(fragments, WrapMode::Words, &options.into())
+}
+
+/// Wrap the given `fragments` iterator by newline characters.
+///
+/// Returns a new `SmallVec` containing the given `fragments` with `Fragment::Line`'s inserted at appropriate places.
+/// See [`Wrapped`] for a version that doesn't allocate.
+#[inline]
+pub fn by_newlines<'a, I>(fragments: I) -> SmallVec<[Fragment<'a>; 4]>
+where
+    I: IntoIterator<Item = Fragment<'a>>,
+{
+    // There's no line limit, so nothing can be cut off
+    wrap(fragments, WrapMode::Newlines, &WrapOptions::new(u16::MAX)).0
+}
+
+fn wrap<'a, I>(
+    fragments: I,
+    mode: WrapMode,
+    options: &WrapOptions<'a>,
+) -> (SmallVec<[Fragment<'a>; 4]>, bool)
+where
+    I: IntoIterator<Item = Fragment<'a>>,
+{
+    if options.area_width == 0 {
+        return (SmallVec::new(), false);
+    }
+
+    if options.max_lines == Some(0) {
+        return (SmallVec::new(), fragments.into_iter().next().is_some());
+    }
+
+    let fragments = fragments.into_iter().collect::<SmallVec<[_; 4]>>();
+
+    if fragments.is_empty() {
+        return (fragments, false);
+    }
+
+    let first = options.line_start(true);
+    let rest = options.line_start(false);
+
+    let mut cuts = SmallVec::<[Cut; 4]>::new();
+    let mut pos = (0, 0);
+    let mut line = first;
+    let mut lines: u16 = 1;
+    let mut line_start = true;
+    let mut ellipsis = false;
+    let mut truncated = false;
+
+    while pos.0 < fragments.len() {
+        if line_start && options.max_lines == Some(lines) {
+            if let Some(cut) = truncate_line(&fragments, pos, mode, line, options) {
+                truncated = true;
+                ellipsis = options.ellipsis.len() <= line.width;
+                cuts.push(cut);
+                break;
+            }
+        }
+
+        let cut = line::next_cut(&fragments, pos, mode, line);
+
+        if cut.line {
+            line = rest;
+            lines = lines.saturating_add(1);
+        }
+
+        line_start = cut.line;
+        pos = cut.end;
+        cuts.push(cut);
+    }
+
+    let rest = options.decoration(rest).collect::<SmallVec<[_; 2]>>();
+    let mut results = SmallVec::with_capacity(fragments.len() + cuts.len() + 1);
+
+    results.extend(options.decoration(first));
+    apply_cuts(&mut results, fragments, &cuts, &rest);
+
+    if ellipsis {
+        results.push(options.ellipsis.clone());
+    }
+
+    (results, truncated)
+}
+
+/// Returns the position where the line after the one beginning at `start` begins, or `None` if it's the last line.
+fn next_line(
+    fragments: &[Fragment],
+    start: Position,
+    mode: WrapMode,
+    line: LineStart,
+) -> Option<Position> {
+    let mut pos = start;
+
+    while pos.0 < fragments.len() {
+        let cut = line::next_cut(fragments, pos, mode, line);
+        pos = cut.end;
+
+        if cut.line {
+            return Some(pos);
+        }
+    }
+
+    None
+}
+
+/// Returns a cut that removes everything after the line beginning at `start`, or `None` if it's the last line.
+///
+/// The content of the line is shortened to leave room for the ellipsis of the given `options`.
+fn truncate_line(
+    fragments: &[Fragment],
+    start: Position,
+    mode: WrapMode,
+    line: LineStart,
+    options: &WrapOptions,
+) -> Option<Cut> {
+    next_line(fragments, start, mode, line)?;
+
+    let end = match line.width.checked_sub(options.ellipsis.len()) {
+        Some(width) if width > 0 => {
+            line::next_cut(fragments, start, mode, LineStart { width, ..line }).start
+        }
+        _ => start,
+    };
+
+    Some(Cut {
+        start: end,
+        end: (fragments.len(), 0),
+        line: false,
+    })
+}
+
+/// Split the given `fragments` at each of the given `cuts`, and push them to `results`.
+///
+/// Each line after the first begins with the given `decoration`.
+fn apply_cuts<'a>(
+    results: &mut SmallVec<[Fragment<'a>; 4]>,
+    fragments: SmallVec<[Fragment<'a>; 4]>,
+    cuts: &[Cut],
+    decoration: &[Fragment<'a>],
+) {
+    let mut cuts = cuts.iter().peekable();
+    let mut resume = (0, 0);
+
+    for (index, fragment) in fragments.into_iter().enumerate() {
+        // This fragment was removed by a previous cut
+        if index < resume.0 {
+            continue;
+        }
+
+        let len = fragment_len(&fragment);
+        let mut from = if index == resume.0 { resume.1 } else { 0 };
+        let mut ranges: SmallVec<[(usize, usize, bool); 2]> = SmallVec::new();
+
+        while let Some(cut) = cuts.next_if(|cut| cut.start.0 == index) {
+            ranges.push((from, cut.start.1, cut.line));
+            resume = cut.end;
+            from = if resume.0 == index { resume.1 } else { len };
+        }
+
+        ranges.push((from, len, false));
+        push_ranges(results, fragment, len, &ranges, decoration);
+    }
+}
+
+/// Push each `(start, end, line)` range of the given `fragment` to `results`, followed by a `Fragment::Line` and the given `decoration` if `line` is true.
+fn push_ranges<'a>(
+    results: &mut SmallVec<[Fragment<'a>; 4]>,
+    fragment: Fragment<'a>,
+    len: usize,
+    ranges: &[(usize, usize, bool)],
+    decoration: &[Fragment<'a>],
+) {
+    // If the whole fragment is kept, then we don't have to copy anything
+    if ranges == [(0, len, false)] {
+        results.push(fragment);
+        return;
+    }
+
+    match fragment {
+        Fragment::Span(span, opts) => {
+            for &(start, end, line) in ranges {
+                if start < end {
+                    results.push(Fragment::Span(slice_span(&span, start, end), opts));
+                }
+
+                if line {
+                    results.push(Fragment::Line);
+                    results.extend(decoration.iter().cloned());
+                }
+            }
+        }
+        fragment => {
+            let mut fragment = Some(fragment);
+
+            for &(start, end, line) in ranges {
+                if start < end {
+                    results.extend(fragment.take());
+                }
+
+                if line {
+                    results.push(Fragment::Line);
+                    results.extend(decoration.iter().cloned());
+                }
+            }
+        }
+    }
+}
+
+/// Returns a span with the content between `start` and `end` of the given `span`, which is only copied if the `span` owns its content.
+fn slice_span<'a>(span: &Span<'a>, start: usize, end: usize) -> Span<'a> {
+    let content = match &span.content {
+        Cow::Borrowed(content) => Cow::Borrowed(&content[start..end]),
+        Cow::Owned(content) => Cow::Owned(content[start..end].to_owned()),
+    };
+
+    Span {
+        content,
+        style: span.style,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by_letters, by_newlines, by_words, wrap, WrapMode, WrapOptions};
+    use crate::widgets::{Fragment, OverflowMode, SpanOptions};
+    use std::array::IntoIter;
+    use tui::{
+        layout::Alignment,
+        style::{Color, Style},
+        text::Span,
+    };
+    use unicode_segmentation::UnicodeSegmentation;
+
+    #[test]
+    fn by_letters_empty() {
+        let fragments = [];
+        let (result, _) = by_letters(IntoIter::new(fragments), 10);
+
+        assert_eq!(result.as_slice(), []);
+    }
+
+    #[test]
+    fn by_letters_no_wrapping() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_letters(fragments.iter().cloned(), 20);
+
+        assert_eq!(result.as_slice(), fragments);
+    }
+
+    #[test]
+    fn by_letters_no_space() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_letters(IntoIter::new(fragments), 0);
+
+        assert_eq!(result.as_slice(), []);
+    }
+
+    #[test]
+    fn by_letters_limited_space() {
+        let fragments = [Fragment::span("test")];
+        let (result, _) = by_letters(IntoIter::new(fragments), 1);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("t"),
+                Fragment::Line,
+                Fragment::span("e"),
+                Fragment::Line,
+                Fragment::span("s"),
+                Fragment::Line,
+                Fragment::span("t")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_wrap_once() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_letters(IntoIter::new(fragments), 10);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is a "),
+                Fragment::Line,
+                Fragment::span("test")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_wrap_multiple_times() {
+        let fragments = [Fragment::span("this is a test of wrapping long sentences")];
+        let (result, _) = by_letters(IntoIter::new(fragments), 10);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is a "),
+                Fragment::Line,
+                Fragment::span("test of wr"),
+                Fragment::Line,
+                Fragment::span("apping lon"),
+                Fragment::Line,
+                Fragment::span("g sentence"),
+                Fragment::Line,
+                Fragment::span("s")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_multiple_spans() {
+        let fragments = [Fragment::span("abcdef"), Fragment::span("gh")];
+        let (result, _) = by_letters(fragments.iter().cloned(), 4);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("abcd"),
+                Fragment::Line,
+                Fragment::span("ef"),
+                Fragment::span("gh")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_no_wrap_at_edge() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_letters(fragments.iter().cloned(), "this is a test".len() as u16);
+
+        assert_eq!(result.as_slice(), fragments);
+    }
+
+    /// Text made up of wide characters, emoji sequences, and combining marks.
+    const UNICODE_CORPUS: [&str; 6] = [
+        "你好世界，这是一个测试",
+        "日本語のテキストを折り返す",
+        "👨\u{200d}👩\u{200d}👧👍🏽🇯🇵❤\u{fe0f}☺\u{fe0f}#\u{fe0f}\u{20e3}",
+        "e\u{301}e\u{301}e\u{301} cafe\u{301} na\u{303}o",
+        "mixed 漢字 and emoji 👩\u{200d}💻 text",
+        "한국어 텍스트를 줄 바꿈합니다",
+    ];
+
+    #[test]
+    fn wrap_unicode_corpus() {
+        for &text in &UNICODE_CORPUS {
+            let boundaries = text
+                .grapheme_indices(true)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>();
+
+            for width in 2..=12 {
+                for &mode in &[WrapMode::Letters, WrapMode::Words] {
+                    let (result, _) = wrap(vec![Fragment::span(text)], mode, &width.into());
+
+                    for line in result.split(|fragment| *fragment == Fragment::Line) {
+                        assert!(
+                            Fragment::total_len(line) <= width,
+                            "{:?} overflowed {} by {:?}: {:?}",
+                            text,
+                            width,
+                            mode,
+                            line
+                        );
+                    }
+
+                    if mode == WrapMode::Words {
+                        continue;
+                    }
+
+                    let mut joined = String::new();
+
+                    for fragment in &result {
+                        if let Fragment::Span(span, _) = fragment {
+                            assert!(
+                                boundaries.contains(&joined.len()),
+                                "{:?} split a grapheme",
+                                text
+                            );
+                            joined.push_str(&span.content);
+                        }
+                    }
+
+                    assert_eq!(joined, text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn by_letters_wide_grapheme_at_edge() {
+        let fragments = [Fragment::span("ab你好")];
+        let (result, _) = by_letters(fragments.iter().cloned(), 3);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("ab"),
+                Fragment::Line,
+                Fragment::span("你"),
+                Fragment::Line,
+                Fragment::span("好")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_combining_marks() {
+        let fragments = [Fragment::span("e\u{301}e\u{301}")];
+        let (result, _) = by_letters(fragments.iter().cloned(), 1);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("e\u{301}"),
+                Fragment::Line,
+                Fragment::span("e\u{301}")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_chars() {
+        let fragments = [
+            Fragment::Char('a', Style::default()),
+            Fragment::Char('你', Style::default()),
+            Fragment::Char('b', Style::default()),
+        ];
+
+        assert_eq!(Fragment::total_len(&fragments), 4);
+
+        let (result, _) = by_letters(fragments.iter().cloned(), 2);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::Char('a', Style::default()),
+                Fragment::Line,
+                Fragment::Char('你', Style::default()),
+                Fragment::Line,
+                Fragment::Char('b', Style::default())
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_zero_width_joiner_chars() {
+        let fragments = [
+            Fragment::Char('👨', Style::default()),
+            Fragment::Char('\u{200d}', Style::default()),
+            Fragment::Char('👩', Style::default()),
+            Fragment::Char('a', Style::default()),
+        ];
+
+        let (result, _) = by_letters(fragments.iter().cloned(), 2);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::Char('👨', Style::default()),
+                Fragment::Char('\u{200d}', Style::default()),
+                Fragment::Char('👩', Style::default()),
+                Fragment::Line,
+                Fragment::Char('a', Style::default())
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_hanging_indent() {
+        let fragments = [Fragment::span("- a list item that wraps")];
+        let options = WrapOptions::new(10).subsequent_indent(2);
+        let (result, _) = by_words(fragments.iter().cloned(), options);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("- a list"),
+                Fragment::Line,
+                Fragment::span("  "),
+                Fragment::span("item"),
+                Fragment::Line,
+                Fragment::span("  "),
+                Fragment::span("that"),
+                Fragment::Line,
+                Fragment::span("  "),
+                Fragment::span("wraps")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_prefix() {
+        let fragments = [Fragment::span("abcdef")];
+
+        let options = WrapOptions::new(4)
+            .subsequent_indent(1)
+            .prefix(Fragment::span("> "));
+
+        let (result, _) = by_letters(fragments.iter().cloned(), options);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("abcd"),
+                Fragment::Line,
+                Fragment::span(" "),
+                Fragment::span("> "),
+                Fragment::span("e"),
+                Fragment::Line,
+                Fragment::span(" "),
+                Fragment::span("> "),
+                Fragment::span("f")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_initial_indent() {
+        let fragments = [Fragment::span("abcdef")];
+        let (result, _) = by_letters(
+            fragments.iter().cloned(),
+            WrapOptions::new(3).initial_indent(2),
+        );
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("  "),
+                Fragment::span("a"),
+                Fragment::Line,
+                Fragment::span("bcd"),
+                Fragment::Line,
+                Fragment::span("ef")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_indent_too_wide() {
+        let fragments = [Fragment::span("abcdef")];
+
+        let options = WrapOptions::new(3)
+            .initial_indent(3)
+            .subsequent_indent(2)
+            .prefix(Fragment::span("> "));
+
+        let (result, _) = by_letters(fragments.iter().cloned(), options);
+
+        assert_eq!(
+            result.as_slice(),
+            [Fragment::span("abc"), Fragment::Line, Fragment::span("def")]
+        );
+    }
+
+    #[test]
+    fn by_letters_max_lines() {
+        let fragments = [Fragment::span("abcdefgh")];
+        let (result, truncated) =
+            by_letters(fragments.iter().cloned(), WrapOptions::new(3).max_lines(2));
+
+        assert!(truncated);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("abc"),
+                Fragment::Line,
+                Fragment::span("de"),
+                Fragment::span("…")
+            ]
+        );
+
+        let (result, truncated) =
+            by_letters(fragments.iter().cloned(), WrapOptions::new(3).max_lines(3));
+
+        assert!(!truncated);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("abc"),
+                Fragment::Line,
+                Fragment::span("def"),
+                Fragment::Line,
+                Fragment::span("gh")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_max_lines_narrow() {
+        let fragments = [Fragment::span("ab cd")];
+
+        let options = WrapOptions::new(2)
+            .max_lines(1)
+            .ellipsis(Fragment::span("..."));
+
+        let (result, truncated) = by_words(fragments.iter().cloned(), options);
+        assert!(result.is_empty());
+        assert!(truncated);
+
+        let options = WrapOptions::new(1).max_lines(1);
+        let (result, _) = by_words(fragments.iter().cloned(), options);
+        assert_eq!(result.as_slice(), [Fragment::span("…")]);
+    }
+
+    #[test]
+    fn by_letters_tabs() {
+        let fragments = [Fragment::span("ab"), Fragment::span("\tc")];
+        let (result, _) = by_letters(fragments.iter().cloned(), WrapOptions::new(4).tab_width(4));
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("ab"),
+                Fragment::span("\t"),
+                Fragment::Line,
+                Fragment::span("c")
+            ]
+        );
+
+        let fragments = [
+            Fragment::span("ab"),
+            Fragment::Char('\t', Style::default()),
+            Fragment::span("c"),
+        ];
+
+        let (result, _) = by_letters(fragments.iter().cloned(), WrapOptions::new(5).tab_width(4));
+        assert_eq!(result.as_slice(), fragments);
+
+        // The indent moves the tab to a column where it no longer fits
+        let options = WrapOptions::new(5).initial_indent(2).tab_width(4);
+        let (result, _) = by_letters(fragments.iter().cloned(), options);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("  "),
+                Fragment::span("ab"),
+                Fragment::Line,
+                Fragment::Char('\t', Style::default()),
+                Fragment::span("c")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_tabs() {
+        let fragments = [Fragment::span("a\tb\tc")];
+        let (result, _) = by_words(fragments.iter().cloned(), WrapOptions::new(8).tab_width(4));
+
+        assert_eq!(
+            result.as_slice(),
+            [Fragment::span("a\tb"), Fragment::Line, Fragment::span("c")]
+        );
+
+        let (result, _) = by_words(fragments.iter().cloned(), WrapOptions::new(9).tab_width(4));
+        assert_eq!(result.as_slice(), [Fragment::span("a\tb\tc")]);
+    }
+
+    #[test]
+    fn by_words_align() {
+        let fragments = [Fragment::Align(Alignment::Center), Fragment::span("aa bb")];
+
+        let (result, _) = by_words(fragments.iter().cloned(), 3);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::Align(Alignment::Center),
+                Fragment::span("aa"),
+                Fragment::Line,
+                Fragment::span("bb")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_fill() {
+        let fragments = [
+            Fragment::span("left"),
+            Fragment::Fill('.', Style::default()),
+            Fragment::span("right"),
+        ];
+
+        let (result, _) = by_words(fragments.iter().cloned(), 9);
+        assert_eq!(result.as_slice(), fragments);
+
+        let (result, _) = by_words(fragments.iter().cloned(), 8);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("left"),
+                Fragment::Line,
+                Fragment::span("right")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_letters_fill() {
+        let fragments = [
+            Fragment::span("left"),
+            Fragment::Fill('.', Style::default()),
+            Fragment::span("right"),
+        ];
+
+        let (result, _) = by_letters(fragments.iter().cloned(), 6);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("left"),
+                Fragment::Fill('.', Style::default()),
+                Fragment::span("ri"),
+                Fragment::Line,
+                Fragment::span("ght")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_cjk() {
+        let fragments = [Fragment::span("你好世界")];
+        let (result, _) = by_words(fragments.iter().cloned(), 5);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("你好"),
+                Fragment::Line,
+                Fragment::span("世界")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_empty() {
+        let fragments = [];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 10);
+
+        assert_eq!(result.as_slice(), []);
+    }
+
+    #[test]
+    fn by_words_no_space() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 0);
+
+        assert_eq!(result.as_slice(), []);
+    }
+
+    #[test]
+    fn by_words_no_wrapping() {
+        let fragments = [Fragment::span("this is a test")];
+        let (result, _) = by_words(fragments.iter().cloned(), 14);
+
+        assert_eq!(result.as_slice(), fragments);
+    }
+
+    #[test]
+    fn by_words_wrap_multiple_times() {
+        let fragments = [Fragment::span("this is a test of wrapping long sentences")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 10);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is a"),
+                Fragment::Line,
+                Fragment::span("test of"),
+                Fragment::Line,
+                Fragment::span("wrapping"),
+                Fragment::Line,
+                Fragment::span("long"),
+                Fragment::Line,
+                Fragment::span("sentences")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_long_word_falls_back_to_letters() {
+        let fragments = [Fragment::span("a verylongword")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 5);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("a"),
+                Fragment::Line,
+                Fragment::span("veryl"),
+                Fragment::Line,
+                Fragment::span("ongwo"),
+                Fragment::Line,
+                Fragment::span("rd")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_after_hyphen() {
+        let fragments = [Fragment::span("well-known")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 6);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("well-"),
+                Fragment::Line,
+                Fragment::span("known")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_keeps_styles_and_options() {
+        let red = Style::default().fg(Color::Red);
+        let opts = SpanOptions::new().overflow(OverflowMode::Truncate);
+
+        let fragments = [
+            Fragment::Span(Span::styled("hello there ", red), opts),
+            Fragment::span("world"),
+        ];
+
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 8);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("hello"),
+                Fragment::Line,
+                Fragment::span("there"),
+                Fragment::Line,
+                Fragment::span("world")
+            ]
+        );
+
+        for fragment in &result[..3] {
+            if let Fragment::Span(span, opts) = fragment {
+                assert_eq!(span.style, red);
+                assert!(matches!(opts.overflow, OverflowMode::Truncate));
+            }
+        }
+    }
+
+    #[test]
+    fn by_words_word_across_spans() {
+        let fragments = [Fragment::span("hel"), Fragment::span("lo world")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 6);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("hel"),
+                Fragment::span("lo"),
+                Fragment::Line,
+                Fragment::span("world")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_line_resets_length() {
+        let fragments = [
+            Fragment::span("aaa bbb"),
+            Fragment::Line,
+            Fragment::span("ccc ddd"),
+        ];
+
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 5);
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("aaa"),
+                Fragment::Line,
+                Fragment::span("bbb"),
+                Fragment::Line,
+                Fragment::span("ccc"),
+                Fragment::Line,
+                Fragment::span("ddd")
+            ]
+        );
+    }
+
+    #[test]
+    fn by_words_whitespace_at_edges() {
+        let fragments = [Fragment::span("  ab cd    ")];
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 5);
+
+        assert_eq!(
+            result.as_slice(),
+            [Fragment::span("  ab"), Fragment::Line, Fragment::span("cd")]
+        );
+    }
+
+    #[test]
+    fn by_words_whitespace_chars() {
+        let fragments = [
+            Fragment::span("abc"),
+            Fragment::Char(' ', Style::default()),
+            Fragment::span("def"),
+        ];
+
+        let (result, _) = by_words(IntoIterator::into_iter(fragments), 4);
+
+        assert_eq!(
+            result.as_slice(),
+            [Fragment::span("abc"), Fragment::Line, Fragment::span("def")]
+        );
+    }
+
+    #[test]
+    fn newlines_empty() {
+        let fragments = [];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(result.as_slice(), []);
+    }
+
+    #[test]
+    fn newlines_no_wrapping() {
+        let fragments = [Fragment::span("this is a no wrapping test")];
+        let result = by_newlines(fragments.iter().cloned());
+
+        assert_eq!(result.as_slice(), fragments);
+    }
+
+    #[test]
+    fn newlines_wrap_once() {
+        let fragments = [Fragment::span("this is a simple\nwrapping test")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is a simple"),
+                Fragment::Line,
+                Fragment::span("wrapping test")
+            ]
+        );
+    }
+
+    #[test]
+    fn newlines_wrap_three_times() {
+        let fragments = [Fragment::span("this is\na simple\nwrapping\ntest")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is"),
+                Fragment::Line,
+                Fragment::span("a simple"),
+                Fragment::Line,
+                Fragment::span("wrapping"),
+                Fragment::Line,
+                Fragment::span("test")
+            ]
+        );
+    }
+
+    #[test]
+    fn newlines_multiple_wraps_at_a_time() {
+        let fragments = [Fragment::span("this is a\n\n\nwrapping test")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::span("this is a"),
+                Fragment::Line,
+                Fragment::Line,
+                Fragment::Line,
+                Fragment::span("wrapping test")
+            ]
+        );
+    }
+
+    #[test]
+    fn newlines_only_wraps() {
+        let fragments = [Fragment::span("\n\n\n")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(
+            result.as_slice(),
+            [Fragment::Line, Fragment::Line, Fragment::Line]
+        );
+    }
+
+    #[test]
+    fn newlines_only_wraps_once() {
+        let fragments = [Fragment::span("\n")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(result.as_slice(), [Fragment::Line]);
+    }
+
+    #[test]
+    fn newlines_starts_with_wrap_ends_with_wrap() {
+        let fragments = [Fragment::span("\nThis is a test!\n")];
+        let result = by_newlines(IntoIter::new(fragments));
+
+        assert_eq!(
+            result.as_slice(),
+            [
+                Fragment::Line,
+                Fragment::span("This is a test!"),
+                Fragment::Line
+            ]
+        );
+    }
+}